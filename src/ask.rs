@@ -1,106 +1,521 @@
-use crate::output::{ko, ok};
-use crate::support::{Language, Support};
+use crate::command::CommandLine;
+use crate::error::{Error, Result};
+use crate::output::{ko, ok, plain};
+use crate::plugin::{detect, plugins, Plugin};
+use crate::support::{walk, Language, Support};
+use crate::task::{BuiltinTask, Category, Provenance, Task, TaskResult};
+use crate::tool::NOT_INSTALLED;
+use crate::trust::trusted;
 use cli_table::{format::Align, format::Justify, Color, Table};
 use crossterm::cursor::Show;
 use crossterm::execute;
 use crossterm::terminal::{Clear, ClearType};
 use inquire::{Confirm, MultiSelect};
 use serde::{Deserialize, Serialize};
-use std::fs::File;
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, File};
 use std::io::{stdout, Write};
-use std::path::Path;
-use std::process::ExitCode;
-
-#[doc = "All checkup title messages"]
-pub const ZUU_TITLES: [&str; 9] = [
-    "Validating the project structure",
-    "Verifying project licenses",
-    "Checking build dependencies",
-    "Scanning for security vulnerabilities",
-    "Running all tests",
-    "Validating code formatting",
-    "Generating project documentation",
-    "Checking for outdated dependencies",
-    "Linting the source code",
-];
-
-#[doc = "All check output messages"]
-pub const OUTPUT_FILES: [&str; 9] = [
-    "project_validation.txt",
-    "license_check.txt",
-    "dependency_checks.txt",
-    "security_audit.txt",
-    "test_results.txt",
-    "formatting_check.txt",
-    "documentation_generation.txt",
-    "dependency_updates.txt",
-    "code_linting.txt",
-];
-
-#[doc = "All checkup success messages"]
-pub const ZUU_OK: [&str; 9] = [
-    "The project is valid.",
-    "No license issues found in dependencies.",
-    "No errors found in packages or dependencies.",
-    "No security vulnerabilities detected.",
-    "All tests passed.",
-    "The code meets the formatting standards.",
-    "Documentation generated successfully.",
-    "All dependencies are up to date.",
-    "The code is validated.",
-];
-
-#[doc = "All checkup failure messages"]
-pub const ZUU_KO: [&str; 9] = [
-    "The project structure is invalid.",
-    "License issues detected in dependencies.",
-    "Errors found in packages or dependencies.",
-    "Security vulnerabilities detected.",
-    "Tests failed.",
-    "Code does not conform to the formatting standards.",
-    "Failed to generate documentation.",
-    "Dependencies are outdated and need updating.",
-    "The code contains errors.",
-];
+use std::iter::once;
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+#[doc = "A user defined task overriding a built-in one"]
+pub struct TaskConfig {
+    pub category: Option<Category>,
+    pub description: Option<String>,
+    #[doc = "A command line, or the list of its arguments"]
+    pub command: Option<CommandLine>,
+    #[doc = "Run the command through `sh -c`, only once tux.toml is trusted"]
+    pub shell: Option<bool>,
+    pub success: Option<String>,
+    pub failure: Option<String>,
+    pub directory: Option<PathBuf>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    pub timeout: Option<u64>,
+}
+
+impl TaskConfig {
+    ///
+    /// # Apply
+    ///
+    /// Replace the task fields defined by the user.
+    ///
+    /// A replaced command runs without a shell unless `shell = true`, and comes from the project.
+    ///
+    pub fn apply(self, task: &mut Task) {
+        if let Some(category) = self.category {
+            task.category = category;
+        }
+        if let Some(description) = self.description {
+            task.description = description;
+        }
+        if let Some(command) = self.command {
+            task.command = command.line();
+            task.shell = self.shell.unwrap_or(false);
+            task.provenance = Provenance::Project;
+        } else if let Some(shell) = self.shell {
+            task.shell = shell;
+            task.provenance = Provenance::Project;
+        }
+        if let Some(success) = self.success {
+            task.success = success;
+        }
+        if let Some(failure) = self.failure {
+            task.failure = failure;
+        }
+        if self.directory.is_some() {
+            task.directory = self.directory;
+        }
+        if self.timeout.is_some() {
+            task.timeout = self.timeout;
+        }
+        task.env.extend(self.env);
+    }
+}
 
 #[derive(Serialize, Deserialize)]
 #[doc = "The tux configuration"]
 pub struct Config {
     pub languages: Vec<String>,
     pub strict: bool,
+    #[serde(default)]
+    pub only: Vec<String>,
+    #[serde(default)]
+    pub skip: Vec<String>,
+    #[serde(default)]
+    pub tasks: BTreeMap<String, BTreeMap<String, TaskConfig>>,
+    #[doc = "The default timeout of every task, in seconds"]
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    #[doc = "Send a desktop notification when a checkup finishes or a task turns red"]
+    pub notify: bool,
+    #[serde(default)]
+    #[doc = "The projects of a monorepo, the current directory is the only project when empty"]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    #[doc = "Find the projects from their marker files when none is declared"]
+    pub discover: bool,
+    #[serde(skip)]
+    #[doc = "The file was approved with tux trust and is unchanged since, its shell tasks can run"]
+    pub trusted: bool,
+    #[serde(skip)]
+    #[doc = "The content of the file, locating the errors found after parsing"]
+    source: String,
 }
 
-pub const PROJECT_STRUCTURE_VALID: &str = "VALID";
-pub const PROJECT_STRUCTURE_NOT_VALID: &str = "NOT VALID";
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[doc = "A project of a monorepo, checked in its own directory"]
+pub struct Project {
+    pub path: PathBuf,
+    #[serde(default)]
+    #[doc = "The languages of the project, the ones of the config detected in its directory when empty"]
+    pub languages: Vec<String>,
+}
 
-pub const PROJECT_LICENSE_VALID: &str = "COMPATIBLE";
-pub const PROJECT_LICENSE_NOT_VALID: &str = "NOT COMPATIBLE";
+impl Project {
+    #[must_use]
+    #[doc = "Get the name of the project, its path without the current directory prefix"]
+    pub fn name(&self) -> String {
+        let name: String = self
+            .path
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => part.to_str(),
+                _ => None,
+            })
+            .collect::<Vec<&str>>()
+            .join("/");
+        if name.is_empty() {
+            return String::from(".");
+        }
+        name
+    }
+}
 
-pub const BUILD_DEPENDENCIES_VALID: &str = "YES";
-pub const BUILD_DEPENDENCIES_NOT_VALID: &str = "NO";
+impl Config {
+    ///
+    /// # Load
+    ///
+    /// Read and parse a tux.toml.
+    ///
+    /// The aliases of the built-in languages, like `js` or `c++`, are replaced by their canonical name.
+    ///
+    /// # Errors
+    ///
+    /// `ConfigMissing` when the file can't be read, `ConfigParse` with the error position
+    ///
+    pub fn load(path: &Path) -> Result<Self> {
+        let Ok(source) = read_to_string(path) else {
+            return Err(Error::ConfigMissing);
+        };
+        let mut config: Self =
+            toml::from_str::<Self>(&source).map_err(|e| Error::config_parse(&source, &e))?;
+        config.trusted = trusted(path);
+        config.source = source;
+        config.languages = config.languages.iter().map(|l| canonical(l)).collect();
+        for project in &mut config.projects {
+            project.languages = project.languages.iter().map(|l| canonical(l)).collect();
+        }
+        let mut tasks: BTreeMap<String, BTreeMap<String, TaskConfig>> = BTreeMap::new();
+        for (language, overrides) in std::mem::take(&mut config.tasks) {
+            tasks
+                .entry(canonical(&language))
+                .or_default()
+                .extend(overrides);
+        }
+        config.tasks = tasks;
+        Ok(config)
+    }
 
-pub const TESTS_RESULT_VALID: &str = "PASS";
-pub const TESTS_RESULTS_NOT_VALID: &str = "FAIL";
+    ///
+    /// # Validate
+    ///
+    /// Check that every language of the config is supported or provided by a plugin, that no task
+    /// name, used as the name of its log files, is a path, that every project is a distinct
+    /// directory of the root, its name being the directory of its logs, and that a task without
+    /// a command overrides a task of its language
+    ///
+    /// # Errors
+    ///
+    /// `UnknownLanguage` with the first unknown name, `ConfigParse` at the first task name
+    /// containing `/`, `\` or `..`, at the first project outside the root or named like
+    /// a previous one, or at the first unknown task without a command
+    ///
+    pub fn validate(&self, plugins: &[Plugin]) -> Result<()> {
+        if let Some(name) = self
+            .tasks
+            .values()
+            .flat_map(BTreeMap::keys)
+            .find(|name| name.contains(['/', '\\']) || name.contains(".."))
+        {
+            return Err(Error::config_at(
+                &self.source,
+                self.source.find(name.as_str()).unwrap_or(0),
+                format!("the task name {name} is a path, it can't contain `/`, `\\` or `..`"),
+            ));
+        }
+        let mut projects: Vec<String> = Vec::new();
+        for project in &self.projects {
+            let path: String = project.path.display().to_string();
+            let offset: usize = self.source.find(path.as_str()).unwrap_or(0);
+            let outside: bool = project.path.is_absolute()
+                || project.path.components().any(|c| {
+                    matches!(
+                        c,
+                        Component::ParentDir | Component::Prefix(_) | Component::RootDir
+                    )
+                });
+            if outside {
+                return Err(Error::config_at(
+                    &self.source,
+                    offset,
+                    format!("the project {path} is outside the current directory"),
+                ));
+            }
+            let name: String = project.name();
+            if projects.contains(&name) {
+                return Err(Error::config_at(
+                    &self.source,
+                    offset,
+                    format!("the project {path} is already declared as {name}"),
+                ));
+            }
+            projects.push(name);
+        }
+        let names = self
+            .languages
+            .iter()
+            .chain(self.projects.iter().flat_map(|project| &project.languages))
+            .chain(self.tasks.keys());
+        for name in names {
+            if name.parse::<Language>().is_err() && !plugins.iter().any(|p| p.name.eq(name)) {
+                return Err(Error::UnknownLanguage { name: name.clone() });
+            }
+        }
+        for (language, overrides) in &self.tasks {
+            let known: Vec<String> = self.known(language, plugins);
+            if let Some(name) = overrides
+                .iter()
+                .find(|(name, custom)| custom.command.is_none() && !known.contains(name))
+                .map(|(name, _)| name)
+            {
+                return Err(Error::config_at(
+                    &self.source,
+                    self.source.find(name.as_str()).unwrap_or(0),
+                    format!("{language} has no task {name} => give it a command to add it"),
+                ));
+            }
+        }
+        Ok(())
+    }
 
-pub const RESPECT_OF_STANDARD_VALID: &str = "RESPECTED";
-pub const RESPECT_OF_STANDARD_NOT_VALID: &str = "NOT RESPECTED";
+    #[doc = "Get the names of the tasks of a language, in the current directory and in every project"]
+    fn known(&self, language: &str, plugins: &[Plugin]) -> Vec<String> {
+        let tasks: Vec<Task> = match language.parse::<Language>() {
+            Ok(builtin) => once(Path::new("."))
+                .chain(self.projects.iter().map(|project| project.path.as_path()))
+                .flat_map(|directory| builtin.project_tasks(directory))
+                .collect(),
+            Err(_) => plugins
+                .iter()
+                .filter(|plugin| plugin.name.eq(language))
+                .flat_map(Plugin::tasks)
+                .collect(),
+        };
+        tasks.into_iter().map(|task| task.name).collect()
+    }
 
-pub const AUDIT_VALID: &str = "YES";
-pub const AUDIT_NOT_VALID: &str = "NO";
+    ///
+    /// # Projects
+    ///
+    /// Get the declared projects, or the discovered ones with `discover`.
+    ///
+    /// A project without languages gets the ones of `languages` detected in its directory.
+    ///
+    #[must_use]
+    pub fn projects(&self, plugins: &[Plugin]) -> Vec<Project> {
+        let projects: Vec<Project> = if self.projects.is_empty() && self.discover {
+            self.discover(Path::new("."), plugins)
+        } else {
+            self.projects.clone()
+        };
+        projects
+            .into_iter()
+            .map(|mut project| {
+                if project.languages.is_empty() {
+                    let detected: Vec<String> = Support::detect(&project.path)
+                        .iter()
+                        .map(ToString::to_string)
+                        .chain(detect(&project.path, plugins))
+                        .collect();
+                    project.languages = self
+                        .languages
+                        .iter()
+                        .filter(|language| detected.contains(language))
+                        .cloned()
+                        .collect();
+                }
+                project
+            })
+            .collect()
+    }
 
-pub const CODE_VALID: &str = "APPROVED";
-pub const CODE_NOT_VALID: &str = "REJECTED";
+    ///
+    /// # Discover
+    ///
+    /// Find the directories of a root having the marker file of a language of the config, like
+    /// `Cargo.toml`, relative to the root.
+    ///
+    /// A directory nested in a project of the same language, like a workspace member, is not a project.
+    ///
+    fn discover(&self, root: &Path, plugins: &[Plugin]) -> Vec<Project> {
+        let builtin = Language::all().into_iter().flat_map(|language| {
+            language
+                .markers()
+                .iter()
+                .map(move |marker| ((*marker).to_string(), language.to_string()))
+        });
+        let provided = plugins.iter().flat_map(|plugin| {
+            plugin
+                .markers
+                .iter()
+                .map(|marker| (marker.clone(), plugin.name.clone()))
+        });
+        let markers: Vec<(String, String)> = builtin
+            .chain(provided)
+            .filter(|(_, language)| self.languages.contains(language))
+            .collect();
+        let mut marked: Vec<(PathBuf, String)> = Vec::new();
+        for path in walk(root) {
+            let (Some(name), Some(parent)) =
+                (path.file_name().and_then(|n| n.to_str()), path.parent())
+            else {
+                continue;
+            };
+            let dir: PathBuf = parent.strip_prefix(root).unwrap_or(parent).to_path_buf();
+            for (_, language) in markers.iter().filter(|(marker, _)| marker.eq(name)) {
+                marked.push((dir.clone(), language.clone()));
+            }
+        }
+        marked.sort_by_key(|(dir, _)| dir.components().count());
+        let mut projects: Vec<Project> = Vec::new();
+        for (dir, language) in marked {
+            if projects
+                .iter()
+                .any(|p| dir.starts_with(&p.path) && p.languages.contains(&language))
+            {
+                continue;
+            }
+            if let Some(project) = projects.iter_mut().find(|p| p.path.eq(&dir)) {
+                project.languages.push(language);
+            } else {
+                projects.push(Project {
+                    path: dir,
+                    languages: vec![language],
+                });
+            }
+        }
+        projects.sort_by(|a, b| a.path.cmp(&b.path));
+        for project in &mut projects {
+            if project.path.as_os_str().is_empty() {
+                project.path = PathBuf::from(".");
+            }
+        }
+        projects
+    }
 
-pub const OUTDATED_VALID: &str = "UP TO DATE";
-pub const OUTDATED_NOT_VALID: &str = "OUTDATED";
+    ///
+    /// # Tasks
+    ///
+    /// Merge the user tasks of a language with the built-in table.
+    ///
+    /// Every field not defined in tux.toml falls back to the built-in value.
+    /// Unknown task names with a command are appended as new tasks.
+    /// Tasks without their own timeout use the global one.
+    ///
+    #[must_use]
+    pub fn tasks(&self, language: &str, defaults: &[BuiltinTask]) -> Vec<Task> {
+        self.merge(language, defaults.iter().map(Task::from).collect())
+    }
 
-pub const DOCUMENTED_VALID: &str = "GENERATED";
-pub const DOCUMENTED_NOT_VALID: &str = "NOT GENERATED";
+    #[must_use]
+    #[doc = "Merge the user tasks of a language with the tasks of a plugin, like `tasks`"]
+    pub fn merge(&self, language: &str, defaults: Vec<Task>) -> Vec<Task> {
+        let mut tasks: Vec<Task> = defaults
+            .into_iter()
+            .map(|task| Task {
+                timeout: task.timeout.or(self.timeout),
+                ..task
+            })
+            .collect();
+        let Some(overrides) = self.tasks.get(language) else {
+            return tasks;
+        };
+        for (name, custom) in overrides {
+            if let Some(task) = tasks.iter_mut().find(|t| t.name.eq(name)) {
+                custom.clone().apply(task);
+            } else if let Some(command) = &custom.command {
+                let category: Category = Category::from_name(name).unwrap_or(Category::Custom);
+                let mut task: Task = Task::new(name, category, command.line().as_str());
+                task.timeout = self.timeout;
+                custom.clone().apply(&mut task);
+                tasks.push(task);
+            }
+        }
+        tasks
+    }
+}
+
+#[doc = "Get the canonical name of a built-in language, or the name itself for a plugin"]
+fn canonical(name: &str) -> String {
+    name.parse::<Language>()
+        .map_or_else(|_| name.to_string(), |language| language.to_string())
+}
+
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,
+)]
+#[serde(rename_all = "snake_case")]
+#[doc = "The outcome of a task, or of a report column, ordered from the best to the worst"]
+pub enum TaskStatus {
+    #[doc = "The task passed"]
+    Pass,
+    #[doc = "The task was disabled by the selection, or the category has no task"]
+    Skipped,
+    #[default]
+    #[doc = "The task was never reached, after a failure in strict mode or a Ctrl-C"]
+    NotRun,
+    #[doc = "The task ran and reported a failure"]
+    Fail,
+    #[doc = "The task was killed after its timeout"]
+    Timeout,
+    #[doc = "The task could not give a verdict: program missing, not started or killed by a signal"]
+    Error,
+}
+
+impl TaskStatus {
+    ///
+    /// # Label
+    ///
+    /// Get the text shown in the report column of a category.
+    ///
+    /// A pass and a fail are named after the category, like `PASS` and `FAIL` for the tests.
+    ///
+    #[must_use]
+    pub const fn label(self, category: Category) -> &'static str {
+        match (self, category) {
+            (Self::Skipped, _) => "SKIPPED",
+            (Self::NotRun, _) => "NOT RUN",
+            (Self::Timeout, _) => "TIMEOUT",
+            (Self::Error, _) => "ERROR",
+            (Self::Pass, Category::Structure) => "VALID",
+            (Self::Fail, Category::Structure) => "NOT VALID",
+            (Self::Pass, Category::License) => "COMPATIBLE",
+            (Self::Fail, Category::License) => "NOT COMPATIBLE",
+            (Self::Pass, Category::Deps | Category::Audit) => "YES",
+            (Self::Fail, Category::Deps | Category::Audit) => "NO",
+            (Self::Pass, Category::Format) => "RESPECTED",
+            (Self::Fail, Category::Format) => "NOT RESPECTED",
+            (Self::Pass, Category::Doc) => "GENERATED",
+            (Self::Fail, Category::Doc) => "NOT GENERATED",
+            (Self::Pass, Category::Outdated) => "UP TO DATE",
+            (Self::Fail, Category::Outdated) => "OUTDATED",
+            (Self::Pass, Category::Lint) => "APPROVED",
+            (Self::Fail, Category::Lint) => "REJECTED",
+            (Self::Pass, Category::Types) => "TYPED",
+            (Self::Fail, Category::Types) => "TYPE ERRORS",
+            (Self::Pass, Category::Test | Category::Custom) => "PASS",
+            (Self::Fail, Category::Test | Category::Custom) => "FAIL",
+        }
+    }
+
+    #[must_use]
+    #[doc = "Check if the status fails the checkup"]
+    pub const fn failed(self) -> bool {
+        matches!(self, Self::Fail | Self::Timeout | Self::Error)
+    }
+}
+
+pub const PROJECT_STRUCTURE_VALID: &str = TaskStatus::Pass.label(Category::Structure);
+pub const PROJECT_STRUCTURE_NOT_VALID: &str = TaskStatus::Fail.label(Category::Structure);
+
+pub const PROJECT_LICENSE_VALID: &str = TaskStatus::Pass.label(Category::License);
+pub const PROJECT_LICENSE_NOT_VALID: &str = TaskStatus::Fail.label(Category::License);
+
+pub const BUILD_DEPENDENCIES_VALID: &str = TaskStatus::Pass.label(Category::Deps);
+pub const BUILD_DEPENDENCIES_NOT_VALID: &str = TaskStatus::Fail.label(Category::Deps);
+
+pub const TESTS_RESULT_VALID: &str = TaskStatus::Pass.label(Category::Test);
+pub const TESTS_RESULTS_NOT_VALID: &str = TaskStatus::Fail.label(Category::Test);
+
+pub const RESPECT_OF_STANDARD_VALID: &str = TaskStatus::Pass.label(Category::Format);
+pub const RESPECT_OF_STANDARD_NOT_VALID: &str = TaskStatus::Fail.label(Category::Format);
+
+pub const AUDIT_VALID: &str = TaskStatus::Pass.label(Category::Audit);
+pub const AUDIT_NOT_VALID: &str = TaskStatus::Fail.label(Category::Audit);
+
+pub const CODE_VALID: &str = TaskStatus::Pass.label(Category::Lint);
+pub const CODE_NOT_VALID: &str = TaskStatus::Fail.label(Category::Lint);
+
+pub const OUTDATED_VALID: &str = TaskStatus::Pass.label(Category::Outdated);
+pub const OUTDATED_NOT_VALID: &str = TaskStatus::Fail.label(Category::Outdated);
+
+pub const DOCUMENTED_VALID: &str = TaskStatus::Pass.label(Category::Doc);
+pub const DOCUMENTED_NOT_VALID: &str = TaskStatus::Fail.label(Category::Doc);
+
+pub const SKIPPED: &str = TaskStatus::Skipped.label(Category::Custom);
+pub const NOT_RUN: &str = TaskStatus::NotRun.label(Category::Custom);
+pub const TIMEOUT: &str = TaskStatus::Timeout.label(Category::Custom);
+pub const ERROR: &str = TaskStatus::Error.label(Category::Custom);
+
+#[doc = "The maximum length of the failure excerpt shown in the summary table"]
+pub const EXCERPT: usize = 60;
 
 pub const SUCCESS: i32 = 0;
 pub const FAILURE: i32 = 1;
 
-#[derive(Table)]
+#[derive(Table, Serialize)]
 pub struct Report {
     #[table(
         title = "LANGUAGE",
@@ -109,6 +524,13 @@ pub struct Report {
         color = "Color::White"
     )]
     pub language: String, // Rust
+    #[table(
+        title = "DIRECTORY",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub project: String, // crates/api
     #[table(
         title = "PROJECT",
         align = "Align::Top",
@@ -139,6 +561,13 @@ pub struct Report {
         color = "Color::White"
     )]
     pub test: String, // PASS | FAIL
+    #[table(
+        title = "TYPES",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub types: String, // Typed | Type errors
     #[table(title = "SECURE", justify = "Justify::Left", color = "Color::White")]
     pub secure: String,
     #[table(
@@ -169,6 +598,20 @@ pub struct Report {
         color = "Color::White"
     )]
     pub lint: String, // Approved | Rejected
+    #[table(
+        title = "DURATION",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub duration: String, // 12.3s (test 11.0s)
+    #[table(
+        title = "FAILURE",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub failure: String, // test: 1 test failed
     #[table(
         title = "EXIT",
         align = "Align::Top",
@@ -176,6 +619,8 @@ pub struct Report {
         color = "Color::White"
     )]
     pub code: i32, // 1 | 0
+    #[table(skip = true)]
+    pub tasks: Vec<TaskResult>,
 }
 
 impl Report {
@@ -183,19 +628,160 @@ impl Report {
     pub fn new() -> Self {
         Self {
             language: Language::Unknown.to_string(),
-            project_structure: PROJECT_STRUCTURE_NOT_VALID.to_uppercase(),
-            licenses: PROJECT_LICENSE_NOT_VALID.to_uppercase(),
-            dependencies: BUILD_DEPENDENCIES_NOT_VALID.to_uppercase(),
-            audit: AUDIT_NOT_VALID.to_uppercase(),
-            test: TESTS_RESULTS_NOT_VALID.to_uppercase(),
-            secure: AUDIT_NOT_VALID.to_string(),
-            standard: RESPECT_OF_STANDARD_NOT_VALID.to_uppercase(),
-            documented: DOCUMENTED_NOT_VALID.to_uppercase(),
-            outdated: OUTDATED_NOT_VALID.to_uppercase(),
-            lint: CODE_NOT_VALID.to_uppercase(),
+            project: String::from("."),
+            project_structure: NOT_RUN.to_string(),
+            licenses: NOT_RUN.to_string(),
+            dependencies: NOT_RUN.to_string(),
+            audit: NOT_RUN.to_string(),
+            test: NOT_RUN.to_string(),
+            types: NOT_RUN.to_string(),
+            secure: NOT_RUN.to_string(),
+            standard: NOT_RUN.to_string(),
+            documented: NOT_RUN.to_string(),
+            outdated: NOT_RUN.to_string(),
+            lint: NOT_RUN.to_string(),
+            duration: String::new(),
+            failure: String::new(),
             code: FAILURE,
+            tasks: Vec::new(),
+        }
+    }
+}
+
+impl Report {
+    #[must_use]
+    #[doc = "Get the name of the report row, the language prefixed by its project"]
+    pub fn name(&self) -> String {
+        if self.project.eq(".") {
+            return self.language.clone();
+        }
+        format!("{}/{}", self.project, self.language)
+    }
+
+    #[doc = "Get the column of a task category"]
+    fn column(&mut self, category: Category) -> Option<&mut String> {
+        match category {
+            Category::Structure => Some(&mut self.project_structure),
+            Category::License => Some(&mut self.licenses),
+            Category::Deps => Some(&mut self.dependencies),
+            Category::Audit => Some(&mut self.audit),
+            Category::Test => Some(&mut self.test),
+            Category::Format => Some(&mut self.standard),
+            Category::Doc => Some(&mut self.documented),
+            Category::Outdated => Some(&mut self.outdated),
+            Category::Lint => Some(&mut self.lint),
+            Category::Types => Some(&mut self.types),
+            Category::Custom => None,
+        }
+    }
+
+    #[doc = "Write a label in the column of a task category, the audit being shown twice"]
+    fn mark(&mut self, category: Category, label: &str) {
+        if category.eq(&Category::Audit) {
+            self.secure = label.to_string();
+        }
+        if let Some(column) = self.column(category) {
+            *column = label.to_string();
+        }
+    }
+
+    #[doc = "Set the column of a task category from its status"]
+    pub fn set(&mut self, category: Category, status: TaskStatus) {
+        self.mark(category, status.label(category));
+    }
+
+    #[doc = "Mark the column of a disabled task category as skipped"]
+    pub fn skip(&mut self, category: Category) {
+        self.set(category, TaskStatus::Skipped);
+    }
+
+    ///
+    /// # Add
+    ///
+    /// Add a task result, updating the column of its category with the worst status of its tasks.
+    ///
+    /// The column is skipped when all its tasks are, and a missing program shows `NOT INSTALLED`.
+    ///
+    pub fn add(&mut self, result: TaskResult) {
+        let category: Category = result.category;
+        self.tasks.push(result);
+        let tasks: Vec<&TaskResult> = self
+            .tasks
+            .iter()
+            .filter(|t| t.category.eq(&category))
+            .collect();
+        let status: TaskStatus = tasks
+            .iter()
+            .map(|t| t.status)
+            .filter(|status| status.ne(&TaskStatus::Skipped))
+            .max()
+            .unwrap_or(TaskStatus::Skipped);
+        if status.eq(&TaskStatus::Error) && tasks.iter().any(|t| t.missing.is_some()) {
+            self.not_installed(category);
+        } else {
+            self.set(category, status);
         }
     }
+
+    #[must_use]
+    #[doc = "Get the worst status of the tasks of the report, skipped when none ran"]
+    pub fn status(&self) -> TaskStatus {
+        self.tasks
+            .iter()
+            .map(|t| t.status)
+            .filter(|status| status.ne(&TaskStatus::Skipped))
+            .max()
+            .unwrap_or(TaskStatus::Skipped)
+    }
+
+    #[doc = "Summarize the duration, the slowest task and the first failure of the executed tasks"]
+    pub fn summarize(&mut self) {
+        let executed: Vec<&TaskResult> = self
+            .tasks
+            .iter()
+            .filter(|t| !matches!(t.status, TaskStatus::Skipped | TaskStatus::NotRun))
+            .collect();
+        let total: f64 = executed.iter().fold(0.0_f64, |sum, t| sum + t.duration);
+        self.duration = executed
+            .iter()
+            .max_by(|a, b| a.duration.total_cmp(&b.duration))
+            .map_or_else(
+                || format!("{total:.1}s"),
+                |slowest| format!("{total:.1}s ({} {:.1}s)", slowest.name, slowest.duration),
+            );
+        self.failure = executed
+            .iter()
+            .find(|t| t.status.failed())
+            .map(|t| excerpt(t))
+            .unwrap_or_default();
+    }
+
+    #[doc = "Mark the column of a task category whose program is not installed"]
+    pub fn not_installed(&mut self, category: Category) {
+        self.mark(category, NOT_INSTALLED);
+    }
+}
+
+#[doc = "Describe the failure of a task in one short line"]
+fn excerpt(task: &TaskResult) -> String {
+    let reason: String = if task.timed_out {
+        TIMEOUT.to_lowercase()
+    } else if let Some(program) = &task.missing {
+        format!("{program} {}", NOT_INSTALLED.to_lowercase())
+    } else if let Some(line) = task.tail.last() {
+        line.trim().to_string()
+    } else if let Some(signal) = task.signal {
+        format!("killed by signal {signal}")
+    } else {
+        format!("exit code {}", task.code.unwrap_or(FAILURE))
+    };
+    let line: String = format!("{}: {reason}", task.name);
+    if line.chars().count() <= EXCERPT {
+        return line;
+    }
+    let mut short: String = line.chars().take(EXCERPT - 3).collect();
+    short.push_str("...");
+    short
 }
 
 impl Default for Report {
@@ -205,11 +791,51 @@ impl Default for Report {
 }
 
 ///
+/// # Init
+///
+/// Generate tux.toml, preselecting the languages detected in the project.
+///
+/// The languages of the plugins are proposed after the built-in ones.
+/// With `yes`, the detected languages are written without any prompt.
 ///
-/// # Panics
-#[must_use]
-pub fn init() -> ExitCode {
+/// # Errors
+///
+/// `NothingDetected` when no language is detected with `yes`, `Io` or `Terminal` on write failure
+///
+pub fn init(yes: bool) -> Result<()> {
     let tux = Path::new("tux.toml");
+    let providers: Vec<Plugin> = plugins();
+    let detected: Vec<Language> = Support::detect(Path::new("."));
+    let found: Vec<String> = detect(Path::new("."), &providers);
+    let supported: Vec<Language> = Support::new().supported();
+    let mut names: Vec<String> = Support::new().all();
+    names.extend(providers.iter().map(|plugin| plugin.name.clone()));
+    let defaults: Vec<usize> = supported
+        .iter()
+        .enumerate()
+        .filter(|(_, language)| detected.contains(language))
+        .map(|(index, _)| index)
+        .chain(
+            providers
+                .iter()
+                .enumerate()
+                .filter(|(_, plugin)| found.contains(&plugin.name))
+                .map(|(index, _)| supported.len() + index),
+        )
+        .collect();
+    if yes {
+        if tux.exists() {
+            return ok("tux.toml already exists", 0);
+        }
+        if defaults.is_empty() {
+            return Err(Error::NothingDetected);
+        }
+        let languages: Vec<String> = defaults
+            .iter()
+            .filter_map(|index| names.get(*index).cloned())
+            .collect();
+        return write_config(tux, &languages, false);
+    }
     if tux.exists()
         && Confirm::new("tux already exist, override ?")
             .with_default(false)
@@ -217,20 +843,23 @@ pub fn init() -> ExitCode {
             .unwrap_or_default()
             .eq(&false)
     {
-        return ExitCode::SUCCESS;
+        return Ok(());
     }
 
-    let languages: Vec<String> = MultiSelect::new(
-        "Select the languages used in your project :",
-        Support::new().all(),
-    )
-    .prompt()
-    .unwrap_or(Vec::from(["Rust".to_string()]));
+    let languages: Vec<String> =
+        MultiSelect::new("Select the languages used in your project :", names)
+            .with_default(&defaults)
+            .prompt()
+            .unwrap_or(Vec::from(["Rust".to_string()]));
     let strict = Confirm::new("Do you want to stop the script on the first failure ?")
         .with_default(false)
         .prompt()
         .unwrap_or_default();
+    write_config(tux, &languages, strict)
+}
 
+#[doc = "Write the generated tux.toml"]
+fn write_config(tux: &Path, languages: &[String], strict: bool) -> Result<()> {
     let config_content: String = format!(
         r#"
 languages = [{}]
@@ -242,15 +871,303 @@ strict = {strict}
             .collect::<Vec<_>>()
             .join(", "),
     );
-    assert!(execute!(stdout(), Show).is_ok());
-    if let Ok(mut conf) = File::create(tux) {
-        assert!(conf.write_all(config_content.as_bytes()).is_ok());
-        assert!(conf.sync_all().is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-        ok("The config has been generated successfully at tux.toml", 0);
-        return ExitCode::SUCCESS;
-    }
-    assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-    ko("Failed to generate config : /tux.toml", 0);
-    ExitCode::FAILURE
+    if !plain() {
+        execute!(stdout(), Show).map_err(Error::Terminal)?;
+    }
+    let written = File::create(tux).and_then(|mut conf| {
+        conf.write_all(config_content.as_bytes())?;
+        conf.sync_all()
+    });
+    if !plain() {
+        execute!(stdout(), Clear(ClearType::All)).map_err(Error::Terminal)?;
+    }
+    if let Err(e) = written {
+        ko("Failed to generate config : /tux.toml", 0)?;
+        return Err(Error::Io(e));
+    }
+    ok("The config has been generated successfully at tux.toml", 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::Exit;
+    use std::{fs, process, time::Duration};
+
+    #[doc = "Load a tux.toml written in a temporary directory"]
+    fn load(name: &str, source: &str) -> Result<Config> {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-config-{name}-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let path: PathBuf = root.join("tux.toml");
+        fs::write(&path, source).unwrap();
+        let config: Result<Config> = Config::load(&path);
+        fs::remove_dir_all(&root).unwrap();
+        config
+    }
+
+    #[test]
+    fn task_names_are_not_paths() {
+        for name in ["../escape", "logs/test", "a\\b", "..", "x..y"] {
+            let source: String = format!(
+                "languages = [\"rust\"]\nstrict = false\n\n[tasks.rust]\n'{name}' = {{ command = \"true\" }}\n"
+            );
+            let config: Config = load("names", &source).unwrap();
+            assert!(matches!(
+                config.validate(&[]),
+                Err(Error::ConfigParse { line: 5, column: 2, message })
+                    if message.starts_with(format!("the task name {name} is a path").as_str())
+            ));
+        }
+        let config: Config = load(
+            "valid",
+            "languages = [\"rust\"]\nstrict = false\n\n[tasks.rust.'e2e-v1.2']\ncommand = \"true\"\n",
+        )
+        .unwrap();
+        assert!(config.validate(&[]).is_ok());
+    }
+
+    #[test]
+    fn projects_stay_distinct_directories_of_the_root() {
+        let header: &str = "languages = [\"rust\"]\nstrict = false\n";
+        for (projects, line, message) in [
+            (
+                "../api",
+                5,
+                "the project ../api is outside the current directory",
+            ),
+            (
+                "/srv/api",
+                5,
+                "the project /srv/api is outside the current directory",
+            ),
+            (
+                "api\"\n\n[[projects]]\npath = \"./api",
+                8,
+                "the project ./api is already declared as api",
+            ),
+        ] {
+            let source: String = format!("{header}\n[[projects]]\npath = \"{projects}\"\n");
+            let config: Config = load("projects", &source).unwrap();
+            assert!(matches!(
+                config.validate(&[]),
+                Err(Error::ConfigParse { line: l, message: m, .. }) if l == line && m == message
+            ));
+        }
+        let source: String =
+            format!("{header}\n[[projects]]\npath = \"api\"\n\n[[projects]]\npath = \"web/api\"\n");
+        let config: Config = load("distinct", &source).unwrap();
+        let names: Vec<String> = config.projects.iter().map(Project::name).collect();
+        assert!(config.validate(&[]).is_ok());
+        assert_eq!(names, ["api", "web/api"]);
+    }
+
+    #[test]
+    fn discover_finds_the_marked_directories() {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-discover-{}", process::id()));
+        for file in [
+            "Cargo.toml",
+            "crates/api/Cargo.toml",
+            "web/package.json",
+            "web/node_modules/left-pad/package.json",
+            "target/package/Cargo.toml",
+            "docs/README.md",
+            "go/go.mod",
+        ] {
+            let path: PathBuf = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        let config: Config = load(
+            "discover",
+            "languages = [\"rust\", \"js\"]\nstrict = false\ndiscover = true\n",
+        )
+        .unwrap();
+        let projects: Vec<Project> = config.discover(&root, &[]);
+        fs::remove_dir_all(&root).unwrap();
+        let found: Vec<(String, Vec<String>)> = projects
+            .iter()
+            .map(|project| (project.name(), project.languages.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (String::from("."), vec![String::from("Rust")]),
+                (String::from("web"), vec![String::from("JavaScript")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_give_their_line_and_column() {
+        let source: &str = "languages = [\"rust\"]\nstrict = false\n\n[tasks.rust.test]\ncommand = \"cargo test\"\ntimeout = \"soon\"\n";
+        let error: Error = load("parse", source).err().unwrap();
+        assert!(matches!(
+            &error,
+            Error::ConfigParse { line: 6, column: 11, message } if message.contains("expected u64")
+        ));
+        assert!(error.to_string().starts_with("tux.toml:6:11: "));
+        let error: Error = load("syntax", "languages = [\"rust\"]\nstrict = \n")
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            Error::ConfigParse {
+                line: 2,
+                column: 10,
+                ..
+            }
+        ));
+    }
+
+    #[doc = "The result of a task exiting with the code after the duration, its last lines given"]
+    fn result(
+        name: &str,
+        category: Category,
+        code: i32,
+        duration: f64,
+        tail: &[&str],
+    ) -> TaskResult {
+        let exit: Exit = Exit {
+            code: Some(code),
+            duration: Duration::from_secs_f64(duration),
+            ..Exit::default()
+        };
+        let task: Task = Task::new(name, category, name);
+        TaskResult {
+            tail: tail.iter().map(ToString::to_string).collect(),
+            ..TaskResult::new(&task, &exit, Path::new("target/zuu-report-none"))
+        }
+    }
+
+    #[test]
+    fn add_keeps_the_worst_status_of_a_column() {
+        let logs: &Path = Path::new("target/zuu-report-none");
+        let mut report: Report = Report::new();
+        report.add(result("unit", Category::Test, 0, 1.0, &[]));
+        assert_eq!(report.test, TESTS_RESULT_VALID);
+        report.add(result("e2e", Category::Test, 1, 1.0, &[]));
+        report.add(result("doc-tests", Category::Test, 0, 1.0, &[]));
+        assert_eq!(report.test, TESTS_RESULTS_NOT_VALID);
+        report.add(TaskResult::skipped(
+            &Task::new("fmt", Category::Format, "fmt"),
+            logs,
+        ));
+        assert_eq!(report.standard, SKIPPED);
+        report.add(result("fmt", Category::Format, 0, 1.0, &[]));
+        assert_eq!(report.standard, RESPECT_OF_STANDARD_VALID);
+        report.add(TaskResult::not_installed(
+            &Task::new("audit", Category::Audit, "cargo audit"),
+            "cargo-audit",
+            logs,
+        ));
+        assert_eq!(report.audit, NOT_INSTALLED);
+        assert_eq!(report.secure, NOT_INSTALLED);
+        report.add(result("bench", Category::Custom, 1, 1.0, &[]));
+        assert_eq!(report.lint, NOT_RUN);
+        assert_eq!(report.tasks.len(), 7);
+        assert_eq!(report.status(), TaskStatus::Error);
+    }
+
+    #[test]
+    fn summarize_gives_the_slowest_task_and_the_first_failure() {
+        let logs: &Path = Path::new("target/zuu-report-none");
+        let mut report: Report = Report::new();
+        report.add(result("fmt", Category::Format, 0, 0.5, &[]));
+        report.add(result(
+            "test",
+            Category::Test,
+            101,
+            2.3,
+            &["running 3 tests", "1 failed "],
+        ));
+        report.add(result("lint", Category::Lint, 1, 1.0, &["warning"]));
+        report.add(TaskResult::not_run(
+            &Task::new("doc", Category::Doc, "doc"),
+            logs,
+        ));
+        report.summarize();
+        assert_eq!(report.duration, "3.8s (test 2.3s)");
+        assert_eq!(report.failure, "test: 1 failed");
+        let mut empty: Report = Report::new();
+        empty.summarize();
+        assert_eq!(empty.duration, "0.0s");
+        assert_eq!(empty.failure, "");
+    }
+
+    #[test]
+    fn excerpt_explains_the_failure_in_one_short_line() {
+        let logs: &Path = Path::new("target/zuu-report-none");
+        let timeout: TaskResult = TaskResult {
+            timed_out: true,
+            ..result("test", Category::Test, 1, 1.0, &["partial"])
+        };
+        let missing: TaskResult = TaskResult::not_installed(
+            &Task::new("audit", Category::Audit, "cargo audit"),
+            "cargo-audit",
+            logs,
+        );
+        let signal: TaskResult = TaskResult {
+            code: None,
+            signal: Some(9),
+            ..result("test", Category::Test, 1, 1.0, &[])
+        };
+        let long: String = "x".repeat(EXCERPT * 2);
+        let truncated: String = excerpt(&result("lint", Category::Lint, 1, 1.0, &[long.as_str()]));
+        assert_eq!(excerpt(&timeout), "test: timeout");
+        assert_eq!(excerpt(&missing), "audit: cargo-audit not installed");
+        assert_eq!(excerpt(&signal), "test: killed by signal 9");
+        assert_eq!(
+            excerpt(&result("test", Category::Test, 3, 1.0, &[])),
+            "test: exit code 3"
+        );
+        assert_eq!(truncated.chars().count(), EXCERPT);
+        assert!(truncated.starts_with("lint: xxx") && truncated.ends_with("..."));
+    }
+
+    #[test]
+    fn merge_overrides_and_extends_the_builtin_tasks() {
+        let config: Config = load(
+            "merge",
+            "languages = [\"rust\"]\nstrict = false\ntimeout = 600\n\n\
+             [tasks.rust.test]\ncommand = [\"cargo\", \"nextest\", \"run\"]\ntimeout = 1800\nenv = { RUST_LOG = \"debug\" }\n\n\
+             [tasks.rust.format]\ndescription = \"Checking the style\"\n\n\
+             [tasks.rust.coverage]\ncommand = \"cargo llvm-cov\"\ndirectory = \"crates/api\"\n",
+        )
+        .unwrap();
+        let defaults: Vec<Task> = Language::Rust.project_tasks(Path::new("."));
+        let tasks: Vec<Task> = config.merge("Rust", defaults.clone());
+        let test: &Task = tasks.iter().find(|t| t.name.eq("test")).unwrap();
+        let fmt: &Task = tasks.iter().find(|t| t.name.eq("format")).unwrap();
+        let builtin: &Task = defaults.iter().find(|t| t.name.eq("format")).unwrap();
+        let coverage: &Task = tasks.last().unwrap();
+        assert!(config.validate(&[]).is_ok());
+        assert_eq!(tasks.len(), defaults.len() + 1);
+        assert_eq!(test.command, "cargo nextest run");
+        assert_eq!(test.provenance, Provenance::Project);
+        assert_eq!(test.timeout, Some(1800));
+        assert_eq!(test.env.get("RUST_LOG").map(String::as_str), Some("debug"));
+        assert_eq!(fmt.description, "Checking the style");
+        assert_eq!(fmt.command, builtin.command);
+        assert_eq!(fmt.provenance, builtin.provenance);
+        assert_eq!(fmt.timeout, Some(600));
+        assert_eq!(coverage.name, "coverage");
+        assert_eq!(coverage.category, Category::Custom);
+        assert_eq!(coverage.command, "cargo llvm-cov");
+        assert_eq!(coverage.directory, Some(PathBuf::from("crates/api")));
+        assert_eq!(coverage.timeout, Some(600));
+    }
+
+    #[test]
+    fn unknown_task_without_command_is_rejected() {
+        let config: Config = load(
+            "unknown",
+            "languages = [\"rust\"]\nstrict = false\n\n[tasks.rust.tset]\ntimeout = 60\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            config.validate(&[]),
+            Err(Error::ConfigParse { line: 4, message, .. })
+                if message.eq("Rust has no task tset => give it a command to add it")
+        ));
+    }
 }
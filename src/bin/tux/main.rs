@@ -1,34 +1,37 @@
 #![allow(clippy::multiple_crate_versions)]
-use clap::{ArgMatches, Command};
-use cli_table::{print_stdout, WithTitle};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, ColorChoice, TableStruct, WithTitle};
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
     execute,
     terminal::{Clear, ClearType},
 };
 use std::{
-    fs::{create_dir_all, read_to_string, File},
-    io::stdout,
+    io::{stdout, ErrorKind, Write},
+    path::{Path, PathBuf},
     process::{Command as Tux, ExitCode},
-    thread::sleep,
-    time::Duration,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    thread::scope,
 };
 use zuu::{
-    ask::{
-        init, Config, Report, AUDIT_NOT_VALID, AUDIT_VALID, BUILD_DEPENDENCIES_NOT_VALID,
-        BUILD_DEPENDENCIES_VALID, CODE_NOT_VALID, CODE_VALID, DOCUMENTED_NOT_VALID,
-        DOCUMENTED_VALID, FAILURE, OUTDATED_NOT_VALID, OUTDATED_VALID, OUTPUT_FILES,
-        PROJECT_LICENSE_NOT_VALID, PROJECT_LICENSE_VALID, PROJECT_STRUCTURE_NOT_VALID,
-        PROJECT_STRUCTURE_VALID, RESPECT_OF_STANDARD_NOT_VALID, RESPECT_OF_STANDARD_VALID, SUCCESS,
-        TESTS_RESULTS_NOT_VALID, TESTS_RESULT_VALID,
-    },
-    output::waiting,
-    runner::create_zuu,
-    support::{Language, Support},
-    BASH_TASK, CPP_TASK, CRYSTAL_TASK, C_TASK, DART_TASK, D_TASK, ELIXIR_TASK, FISH_TASK,
-    FSHARP_TASK, GO_TASK, HASKELL_TASK, JAVA_TASK, KOTLIN_TASK, LUA_TASK, NIM_TASK, NODEJS_TASK,
-    OBJC_TASK, PERL_TASK, PHP_TASK, PYTHON_TASK, RUBY_TASK, RUST_TASK, R_TASK, SCALA_TASK,
-    SWIFT_TASK, ZSH_TASK,
+    ask::{init, Config, Project, Report, TaskStatus, FAILURE, SUCCESS},
+    cache::Cache,
+    export::{export, render, Format},
+    hook::{install, staged, uninstall, HOOKS},
+    notify::{regressions, Notifier},
+    output::{interrupt, interrupted, ko, ok, plain, set_plain, set_quiet, title, waiting},
+    plugin::{plugins, Plugin},
+    render::{auto, from_name, Renderer},
+    runner::{create_zuu, TaskRunner},
+    support::{fixture, tree, Language, Support},
+    task::{Category, Selection, Task, TaskResult},
+    tool::{self, Tool, NOT_INSTALLED},
+    trust::trust,
+    watch::{languages, Watch, DEBOUNCE},
+    Error, Result,
 };
 
 #[doc = "command line options"]
@@ -46,333 +49,913 @@ by using the strict mode. Tux also helps you set up source tracking by creating
         .about(
             "Tux checks your project’s source code quality by running various validation tasks."
         )
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .short('j')
+                .help("Number of languages to check at the same time")
+                .value_parser(value_parser!(usize))
+                .default_value("1")
+                .global(true),
+        )
+        .arg(
+            Arg::new("only")
+                .long("only")
+                .help("Run only the given tasks or categories, separated by commas")
+                .value_delimiter(',')
+                .global(true),
+        )
+        .arg(
+            Arg::new("skip")
+                .long("skip")
+                .help("Skip the given tasks or categories, separated by commas")
+                .value_delimiter(',')
+                .global(true),
+        )
+        .arg(
+            Arg::new("plain")
+                .long("plain")
+                .help("Print plain lines without cursor control, default when stdout is not a terminal")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("renderer")
+                .long("renderer")
+                .help("Show the progress with crossterm, indicatif, plain lines or nothing")
+                .value_parser(["crossterm", "indicatif", "plain", "silent"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("Export the reports in a machine-readable format")
+                .value_parser(["json", "junit", "sarif"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .help("The file receiving the exported reports")
+                .value_parser(value_parser!(PathBuf))
+                .requires("format")
+                .global(true),
+        )
         .subcommand(
             Command::new("strict")
                 .about("Exit on the first failure during validation")
                 .subcommand_required(false),
         )
         .subcommand(Command::new("watch").about("enable watch mode"))
+        .arg(
+            Arg::new("staged")
+                .long("staged")
+                .help("Check only the languages with staged files")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("no-cache")
+                .long("no-cache")
+                .help("Rerun every task, even when its inputs are unchanged")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .subcommand(
+            Command::new("hook")
+                .about("Manage the git hooks running tux")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("install")
+                        .about("Install or chain the git hooks")
+                        .args(hook_flags()),
+                )
+                .subcommand(
+                    Command::new("uninstall")
+                        .about("Remove tux from the git hooks")
+                        .args(hook_flags()),
+                ),
+        )
+        .subcommand(
+            Command::new("doctor")
+                .about("Check that the programs of the configured tasks are installed"),
+        )
+        .subcommand(
+            Command::new("trust")
+                .about("Allow the shell tasks of tux.toml to run, until the file changes"),
+        )
         .subcommand(
             Command::new("init")
                 .about("Initialize the source tracking by creating a tux.toml configuration file")
-                .subcommand_required(false),
+                .subcommand_required(false)
+                .arg(
+                    Arg::new("yes")
+                        .long("yes")
+                        .short('y')
+                        .help("Write the detected languages without prompting")
+                        .action(ArgAction::SetTrue),
+                ),
         )
         .get_matches()
 }
 
-pub fn zuu() -> (Vec<Report>, i32) {
-    let reports: Vec<Report> = check_source_code();
+#[doc = "The flags selecting the git hooks"]
+fn hook_flags() -> [Arg; 2] {
+    HOOKS.map(|hook| {
+        Arg::new(hook)
+            .long(hook)
+            .help(format!("Select the {hook} hook, the default"))
+            .action(ArgAction::SetTrue)
+    })
+}
 
-    assert!(execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).is_ok());
-    assert!(print_stdout(reports.with_title()).is_ok());
-    for report in &reports {
-        if report.code.eq(&1) {
-            return (reports, FAILURE);
+///
+/// # Hook
+///
+/// Install or uninstall the selected git hooks, the pre-commit one by default
+///
+fn hook(app: &ArgMatches) -> Result<()> {
+    let Some((action, flags)) = app.subcommand() else {
+        return Ok(());
+    };
+    let mut hooks: Vec<&str> = HOOKS
+        .into_iter()
+        .filter(|hook| flags.get_flag(hook))
+        .collect();
+    if hooks.is_empty() {
+        hooks.push("pre-commit");
+    }
+    for (line, hook) in hooks.into_iter().enumerate() {
+        let done = if action.eq("install") {
+            install(hook)
+        } else {
+            uninstall(hook)
+        };
+        if let Err(e) = done {
+            ko(format!("Failed to {action} the {hook} hook").as_str(), line)?;
+            return Err(e);
         }
+        ok(
+            format!("The {hook} hook has been {action}ed").as_str(),
+            line,
+        )?;
+    }
+    Ok(())
+}
+
+#[doc = "The command line options of a checkup"]
+#[derive(Clone, Default)]
+pub struct Options {
+    pub jobs: usize,
+    #[doc = "Stop on the first failure, even when tux.toml is not strict"]
+    pub strict: bool,
+    pub only: Vec<String>,
+    pub skip: Vec<String>,
+    #[doc = "Restrict the checkup to these languages, by name, when not empty"]
+    pub languages: Vec<String>,
+    #[doc = "Run the fast tasks first and stop on their failure"]
+    pub fast_first: bool,
+    #[doc = "The renderer of the progress, the one of the output mode when not set"]
+    pub renderer: Option<Arc<dyn Renderer>>,
+    #[doc = "The language providers of the plugin directory"]
+    pub plugins: Vec<Plugin>,
+    #[doc = "Restrict the projects to the ones containing one of these paths when not empty"]
+    pub paths: Vec<PathBuf>,
+    #[doc = "Rerun every task, ignoring the cached results"]
+    pub no_cache: bool,
+}
+
+#[doc = "A language checked in the current directory or in a project"]
+struct Target {
+    language: String,
+    project: Option<Project>,
+    tasks: Vec<Task>,
+    #[doc = "The files of the language hashed by the cache"]
+    inputs: Vec<PathBuf>,
+    #[doc = "The test fixtures hashed by the cache for the test and custom tasks"]
+    fixtures: Vec<PathBuf>,
+}
+
+impl Target {
+    #[doc = "Get the title of the target, the language followed by its project"]
+    fn title(&self) -> String {
+        self.project.as_ref().map_or_else(
+            || self.language.clone(),
+            |project| format!("{} ({})", self.language, project.name()),
+        )
+    }
+
+    #[doc = "Get the log directory, `zuu/<project>/<language>` in a project"]
+    fn logs(&self) -> PathBuf {
+        let zuu: PathBuf = PathBuf::from("zuu");
+        self.project.as_ref().map_or_else(
+            || zuu.join(&self.language),
+            |project| zuu.join(project.name()).join(&self.language),
+        )
+    }
+}
+
+#[doc = "The settings shared by every checked language"]
+struct Checkup {
+    strict: bool,
+    fast_first: bool,
+    cache: bool,
+    selection: Selection,
+    renderer: Arc<dyn Renderer>,
+}
+
+///
+/// # Zuu
+///
+/// Check the source code, the reports are partial when interrupted
+///
+/// # Errors
+///
+/// On config, terminal or io failure
+///
+pub fn zuu(options: &Options) -> Result<Vec<Report>> {
+    check_source_code(options)
+}
+
+///
+/// # Status
+///
+/// Get the outcome of the checkup from the worst task status
+///
+/// # Errors
+///
+/// `Interrupted` on Ctrl-C, `TaskError` when a task gave no verdict, `Timeout` when a task timed out,
+/// `NonZeroExit` on check failure
+///
+fn status(reports: &[Report]) -> Result<()> {
+    if interrupted() {
+        return Err(Error::Interrupted);
+    }
+    match reports.iter().map(Report::status).max() {
+        Some(TaskStatus::Timeout) => Err(Error::Timeout),
+        Some(TaskStatus::Fail) => Err(Error::NonZeroExit {
+            code: Some(FAILURE),
+        }),
+        Some(TaskStatus::Error) => Err(Error::TaskError {
+            tasks: reports
+                .iter()
+                .flat_map(|r| {
+                    r.tasks
+                        .iter()
+                        .filter(|t| t.status.eq(&TaskStatus::Error))
+                        .map(|t| format!("{}/{}", r.name(), t.name))
+                })
+                .collect(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[doc = "Map every error to a distinct exit code"]
+fn exit_code(error: &Error) -> ExitCode {
+    ExitCode::from(match error {
+        Error::NonZeroExit { .. } => 1,
+        Error::ConfigMissing => 3,
+        Error::NothingDetected => 13,
+        Error::ConfigParse { .. } | Error::UnknownLanguage { .. } => 4,
+        Error::Plugin { .. } => 9,
+        Error::Untrusted { .. } => 10,
+        Error::Command { .. } => 11,
+        Error::TaskError { .. } => 12,
+        Error::SpawnFailed { .. } => 5,
+        Error::Timeout => 6,
+        Error::Terminal(_) => 7,
+        Error::Io(_) => 8,
+        Error::Interrupted => 130,
+    })
+}
+
+#[doc = "Build the reports table, without colors in plain mode"]
+fn table(reports: &[Report]) -> TableStruct {
+    let table: TableStruct = reports.with_title();
+    if plain() {
+        return table.color_choice(ColorChoice::Never);
     }
-    (reports, SUCCESS)
+    table
 }
 
-fn report(reports: Vec<Report>) {
-    assert!(execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).is_ok());
-    assert!(print_stdout(reports.with_title()).is_ok());
-    assert!(execute!(stdout(), Show).is_ok());
+///
+/// # Print
+///
+/// Write a rendered report to stdout.
+///
+/// A reader closing the pipe early, like `head`, is not an error.
+///
+/// # Errors
+///
+/// `Io` when stdout can't be written
+///
+fn print(content: &str) -> Result<()> {
+    match writeln!(stdout().lock(), "{content}") {
+        Err(e) if e.kind().ne(&ErrorKind::BrokenPipe) => Err(Error::Io(e)),
+        _ => Ok(()),
+    }
 }
+
+fn report(reports: &[Report], format: Option<Format>, output: Option<&PathBuf>) -> Result<()> {
+    if !plain() {
+        execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).map_err(Error::Terminal)?;
+    }
+    match (format, output) {
+        (Some(format), Some(output)) => {
+            print_stdout(table(reports)).map_err(Error::Terminal)?;
+            export(reports, format, output)?;
+        }
+        (Some(format), None) => print(render(reports, format).as_str())?,
+        _ => print_stdout(table(reports)).map_err(Error::Terminal)?,
+    }
+    if !plain() {
+        execute!(stdout(), Show).map_err(Error::Terminal)?;
+    }
+    Ok(())
+}
+
 #[must_use]
 pub fn main() -> ExitCode {
-    assert!(execute!(
-        stdout(),
-        Clear(crossterm::terminal::ClearType::All),
-        Hide,
-        MoveTo(0, 0)
-    )
-    .is_ok());
-    assert!(create_zuu().is_ok());
     let app: ArgMatches = tux();
-    if app.subcommand_matches("init").is_some() && app.subcommand_matches("watch").is_none() {
-        return init();
+    let renderer: Option<&String> = app.get_one::<String>("renderer");
+    set_plain(app.get_flag("plain") || renderer.is_some_and(|r| r.ne("crossterm")));
+    set_quiet(renderer.is_some_and(|r| r.eq("silent")));
+    match run(&app) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            if !plain() {
+                let _ = execute!(stdout(), Show);
+            }
+            match &e {
+                Error::NonZeroExit { .. } => {}
+                Error::Interrupted => eprintln!("{e}, the report is partial"),
+                _ => eprintln!("tux: {e}"),
+            }
+            exit_code(&e)
+        }
     }
+}
 
-    if app.subcommand_matches("watch").is_some() {
-        loop {
-            report(zuu().0);
-            sleep(Duration::from_secs(60));
+///
+/// # Run
+///
+/// Execute the tux command
+///
+/// # Errors
+///
+/// On check failure, or on config, terminal or io failure
+///
+fn run(app: &ArgMatches) -> Result<()> {
+    if !plain() {
+        execute!(stdout(), Clear(ClearType::All), Hide, MoveTo(0, 0)).map_err(Error::Terminal)?;
+    }
+    create_zuu()?;
+    if let Some(init_matches) = app.subcommand_matches("init") {
+        return init(init_matches.get_flag("yes"));
+    }
+
+    if let Some(hooks) = app.subcommand_matches("hook") {
+        return hook(hooks);
+    }
+
+    if app.subcommand_matches("trust").is_some() {
+        return trust_config();
+    }
+
+    let mut options: Options = Options {
+        jobs: app.get_one::<usize>("jobs").copied().unwrap_or(1).max(1),
+        strict: app.subcommand_matches("strict").is_some(),
+        only: app
+            .get_many::<String>("only")
+            .map(|v| v.cloned().collect())
+            .unwrap_or_default(),
+        skip: app
+            .get_many::<String>("skip")
+            .map(|v| v.cloned().collect())
+            .unwrap_or_default(),
+        languages: Vec::new(),
+        fast_first: false,
+        renderer: app
+            .get_one::<String>("renderer")
+            .and_then(|name| from_name(name)),
+        plugins: plugins(),
+        paths: Vec::new(),
+        no_cache: app.get_flag("no-cache"),
+    };
+    if app.get_flag("staged") {
+        let files: Vec<PathBuf> = match staged() {
+            Ok(files) => files,
+            Err(e) => {
+                ko("Failed to list the staged files", 0)?;
+                return Err(e);
+            }
+        };
+        options.languages = changed(&files, &options.plugins);
+        options.paths = files;
+        if options.languages.is_empty() {
+            return ok("No staged source file to check", 0);
         }
     }
-    let r = zuu();
-    report(r.0);
-    if r.1.eq(&FAILURE) {
-        ExitCode::FAILURE
-    } else {
-        ExitCode::SUCCESS
+    if app.subcommand_matches("doctor").is_some() {
+        return doctor(&options);
     }
+    let format: Option<Format> = app
+        .get_one::<String>("format")
+        .and_then(|f| Format::from_name(f));
+    let output: Option<&PathBuf> = app.get_one::<PathBuf>("output");
+    ctrlc::set_handler(interrupt).map_err(|e| Error::Io(std::io::Error::other(e)))?;
+    if app.subcommand_matches("watch").is_some() {
+        return watch(&options, format, output);
+    }
+    let reports: Vec<Report> = zuu(&options)?;
+    report(&reports, format, output)?;
+    notify(&reports, &[]);
+    status(&reports)
 }
 
-#[doc = "load user configuration"]
-pub fn load_config() -> Config {
-    if let Ok(config) = read_to_string("tux.toml") {
-        if let Ok(tux) = toml::from_str::<Config>(&config) {
-            return tux;
-        }
+///
+/// # Trust
+///
+/// Approve the current tux.toml, its shell tasks run until it changes
+///
+/// # Errors
+///
+/// `ConfigMissing` or `ConfigParse` on an invalid tux.toml, `Io` when the approval can't be written
+///
+fn trust_config() -> Result<()> {
+    let path: &Path = Path::new("tux.toml");
+    Config::load(path)?;
+    let copy: PathBuf = trust(path)?;
+    ok(
+        format!("tux.toml trusted, approved copy in {}", copy.display()).as_str(),
+        0,
+    )
+}
+
+#[doc = "Send the desktop notification of a finished checkup when enabled in tux.toml"]
+fn notify(reports: &[Report], red: &[String]) {
+    if interrupted() || reports.is_empty() {
+        return;
+    }
+    if !Config::load(Path::new("tux.toml")).is_ok_and(|config| config.notify) {
+        return;
+    }
+    let notifier: Notifier = Notifier::default();
+    if let Some(reason) = notifier.missing() {
+        eprintln!("tux: no desktop notification, {reason}");
+    } else if !notifier.finished(reports, red) {
+        eprintln!("tux: the desktop notification could not be delivered");
     }
-    assert!(execute!(stdout(), Show).is_ok());
-    panic!("Failed to load config => run tux init");
 }
 
-pub fn contains_dangerous_chars(command: &str) -> bool {
-    let dangerous_chars = [
-        "/",  // Root directory expansion
-        "./", // Execution
-        ";",  // Command separator
-        "&",  // Background execution
-        "|",  // Pipe to chain commands
-        "`",  // Command substitution
-        "$(", // Start of command substitution (alternative to `)
-        "${", // Variable substitution (can be used for injection)
-        ">",  // Output redirection
-        "<",  // Input redirection
-        ">>", // Append output redirection
-        "<<", // Multiple input redirection
-        "*",  // Wildcard (can be exploited)
-        "?",  // Wildcard (can be exploited)
-        "[",  // Start of a regex or character class (can be used in commands)
-        "]",  // End of a regex or character class
-        "~",  // Home directory expansion
-        "^",  // Substitution in some shells
-        "!",  // Command history in some shells
-        "\\", // Escape character
-        "||", // Logical OR
-        "&&", // Logical AND
-        "(",  // Open parentheses (used for subshells or grouping)
-        ")",  // Close parentheses
-        "#",  // Comment in many shells (can hide parts of commands)
-    ];
-    dangerous_chars.iter().any(|&c| command.contains(c))
+///
+/// # Doctor
+///
+/// Print the program of every configured task, with its version or its install hint
+///
+/// # Errors
+///
+/// On config or terminal failure, `NonZeroExit` when a program is not installed
+///
+fn doctor(options: &Options) -> Result<()> {
+    let config: Config = Config::load(Path::new("tux.toml"))?;
+    config.validate(&options.plugins)?;
+    let tasks: Vec<(String, Vec<Task>)> = targets(&config, options)
+        .into_iter()
+        .map(|target| (target.title(), target.tasks))
+        .collect();
+    let tools: Vec<Tool> = tool::doctor(&tasks);
+    if !plain() {
+        execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).map_err(Error::Terminal)?;
+    }
+    let table: TableStruct = tools.with_title();
+    let table: TableStruct = if plain() {
+        table.color_choice(ColorChoice::Never)
+    } else {
+        table
+    };
+    print_stdout(table).map_err(Error::Terminal)?;
+    if tools.iter().any(|tool| tool.status.eq(NOT_INSTALLED)) {
+        return Err(Error::NonZeroExit { code: Some(1) });
+    }
+    Ok(())
 }
 
 ///
-/// # Panics
+/// # Watch
 ///
-/// On failed parse config or crossterm faillure
+/// Check all languages, then rerun only the languages whose files changed
 ///
-#[must_use]
-pub fn check_source_code() -> Vec<Report> {
-    let config: Config = load_config();
-    let mut reports: Vec<Report> = Vec::new();
-    let to_check: Vec<String> = config.languages;
-    for lang in &Support::new().supported() {
-        if to_check.contains(&lang.to_string()) {
-            if let Ok(report) = source_code_verify(lang, config.strict) {
-                reports.push(report);
+fn watch(options: &Options, format: Option<Format>, output: Option<&PathBuf>) -> Result<()> {
+    let mut reports: Vec<Report> = zuu(options)?;
+    report(&reports, format, output)?;
+    notify(&reports, &[]);
+    let watcher: Watch = match Watch::new(Path::new("."), DEBOUNCE) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            ko("Failed to watch the project tree", 0)?;
+            return Err(e);
+        }
+    };
+    while let Some(paths) = watcher.changes() {
+        let languages: Vec<String> = changed(&paths, &options.plugins);
+        if languages.is_empty() {
+            continue;
+        }
+        let rerun: Options = Options {
+            languages,
+            paths,
+            fast_first: true,
+            ..options.clone()
+        };
+        let checked: Vec<Report> = zuu(&rerun)?;
+        let red: Vec<String> = regressions(&reports, &checked);
+        for fresh in checked {
+            if let Some(old) = reports.iter_mut().find(|r| r.name().eq(&fresh.name())) {
+                *old = fresh;
+            } else {
+                reports.push(fresh);
             }
         }
+        report(&reports, format, output)?;
+        notify(&reports, &red);
     }
-    reports
+    status(&reports)
+}
+
+#[doc = "Get the names of the built-in and plugin languages owning at least one of the paths"]
+fn changed(paths: &[PathBuf], plugins: &[Plugin]) -> Vec<String> {
+    languages(paths)
+        .iter()
+        .map(ToString::to_string)
+        .chain(zuu::plugin::languages(paths, plugins))
+        .collect()
 }
 
-fn source_code_verify(l: &Language, strict: bool) -> std::io::Result<Report> {
-    assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-    let mut results: Vec<bool> = Vec::new();
-
-    let todo = match l {
-        Language::Rust => RUST_TASK,
-        Language::Go => GO_TASK,
-        Language::D => D_TASK,
-        Language::Python => PYTHON_TASK,
-        Language::Php => PHP_TASK,
-        Language::Unknown => RUST_TASK,
-        Language::JavaScript => NODEJS_TASK,
-        Language::TypeScript => NODEJS_TASK,
-        Language::Java => JAVA_TASK,
-        Language::Kotlin => KOTLIN_TASK,
-        Language::Swift => SWIFT_TASK,
-        Language::Scala => SCALA_TASK,
-        Language::Ruby => RUBY_TASK,
-        Language::Perl => PERL_TASK,
-        Language::R => R_TASK,
-        Language::Haskell => HASKELL_TASK,
-        Language::Lua => LUA_TASK,
-        Language::ObjectiveC => OBJC_TASK,
-        Language::C => C_TASK,
-        Language::Cpp => CPP_TASK,
-        Language::Nim => NIM_TASK,
-        Language::Crystal => CRYSTAL_TASK,
-        Language::FSharp => FSHARP_TASK,
-        Language::Dart => DART_TASK,
-        Language::Elixir => ELIXIR_TASK,
-        Language::Bash => BASH_TASK,
-        Language::Zsh => ZSH_TASK,
-        Language::Fish => FISH_TASK,
+///
+/// # Errors
+///
+/// On failed parse config, crossterm or io failure
+///
+pub fn check_source_code(options: &Options) -> Result<Vec<Report>> {
+    let config: Config = Config::load(Path::new("tux.toml"))?;
+    config.validate(&options.plugins)?;
+    let targets: Vec<Target> = targets(&config, options)
+        .into_iter()
+        .filter(|t| options.languages.is_empty() || options.languages.contains(&t.language))
+        .filter(|t| {
+            options.paths.is_empty() || t.project.as_ref().is_none_or(|p| owns(p, &options.paths))
+        })
+        .map(|mut target| {
+            if options.fast_first {
+                target.tasks.sort_by_key(|task| !task.category.fast());
+            }
+            target
+        })
+        .collect();
+    let selection: Selection = Selection::new(&config.only, &config.skip)
+        .with_env()
+        .with(&options.only, &options.skip);
+    for target in &targets {
+        for task in target.tasks.iter().filter(|task| selection.enabled(task)) {
+            task.check(target.title().as_str(), config.trusted)?;
+        }
+    }
+    let checkup: Checkup = Checkup {
+        strict: config.strict || options.strict,
+        fast_first: options.fast_first,
+        cache: !options.no_cache,
+        selection,
+        renderer: options.renderer.clone().unwrap_or_else(auto),
     };
-    assert!(create_dir_all(format!("zuu/{l}")).is_ok());
-    assert!(create_dir_all(format!("zuu/{l}/stderr")).is_ok());
-    assert!(create_dir_all(format!("zuu/{l}/stdout")).is_ok());
-    let mut waiting_line: usize = 0;
-    let mut ret: Report = Report::new();
-    ret.language = l.to_string();
-    for (index, command) in todo.iter().enumerate() {
-        waiting_line = index;
-        if contains_dangerous_chars(command.1) {
-            assert!(waiting(
-                (
-                    format!(
-                        "Stopped bedore task {}: {}/9. Dangerous command founded",
-                        command.0,
-                        index + 1,
-                    ),
-                    "Ok let's go".to_string(),
-                    "Oops".to_string()
-                ),
-                Tux::new("sleep").arg("10"),
-                waiting_line
-            )
-            .is_ok());
-            assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
+    if options.jobs > 1 {
+        return check_in_parallel(&targets, &checkup, options.jobs);
+    }
+    let mut reports: Vec<Report> = Vec::new();
+    for target in &targets {
+        if interrupted() {
             break;
         }
-        let data: (String, String, String) = (
-            command.0.to_string(), // title
-            command.2.to_string(), // success
-            command.3.to_string(), // failure
-        );
-        let report_error: bool = waiting(
-            data,
-            Tux::new("sh")
-                .arg("-c")
-                .arg(command.1)
-                .stderr(
-                    File::create(
-                        format!(
-                            "zuu/{l}/stderr/{}",
-                            OUTPUT_FILES.get(index).unwrap_or(&"default")
-                        )
-                        .as_str(),
-                    )
-                    .expect(""),
-                )
-                .stdout(
-                    File::create(
-                        format!(
-                            "zuu/{l}/stdout/{}",
-                            OUTPUT_FILES.get(index).unwrap_or(&"default")
-                        )
-                        .as_str(),
-                    )
-                    .expect("msg"),
-                ),
-            index,
-        )
-        .is_err();
-        results.push(report_error);
-        match index {
-            0 => {
-                ret.project_structure = if report_error {
-                    PROJECT_STRUCTURE_NOT_VALID.to_uppercase()
-                } else {
-                    PROJECT_STRUCTURE_VALID.to_uppercase()
-                }
-            }
-            1 => {
-                ret.licenses = if report_error {
-                    PROJECT_LICENSE_NOT_VALID.to_uppercase()
-                } else {
-                    PROJECT_LICENSE_VALID.to_uppercase()
-                }
-            }
-            2 => {
-                ret.dependencies = if report_error {
-                    BUILD_DEPENDENCIES_NOT_VALID.to_uppercase()
-                } else {
-                    BUILD_DEPENDENCIES_VALID.to_uppercase()
-                }
-            }
-            3 => {
-                ret.audit = if report_error {
-                    AUDIT_NOT_VALID.to_uppercase()
-                } else {
-                    AUDIT_VALID.to_uppercase()
-                }
-            }
-            4 => {
-                ret.test = if report_error {
-                    TESTS_RESULTS_NOT_VALID.to_uppercase()
-                } else {
-                    TESTS_RESULT_VALID.to_uppercase()
-                }
-            }
-            5 => {
-                ret.standard = if report_error {
-                    RESPECT_OF_STANDARD_NOT_VALID.to_uppercase()
-                } else {
-                    RESPECT_OF_STANDARD_VALID.to_uppercase()
-                }
-            }
-            6 => {
-                ret.documented = if report_error {
-                    DOCUMENTED_NOT_VALID.to_uppercase()
-                } else {
-                    DOCUMENTED_VALID.to_uppercase()
-                }
-            }
-            7 => {
-                ret.outdated = if report_error {
-                    OUTDATED_NOT_VALID.to_uppercase()
-                } else {
-                    OUTDATED_VALID.to_uppercase()
-                }
+        clear(None)?;
+        reports.push(source_code_verify(target, &checkup, None)?);
+    }
+    Ok(reports)
+}
+
+///
+/// # Targets
+///
+/// Get the languages to check, in the current directory or in every project of tux.toml.
+///
+/// The tasks of a project run in its directory.
+///
+fn targets(config: &Config, options: &Options) -> Vec<Target> {
+    let projects: Vec<Project> = config.projects(&options.plugins);
+    if projects.is_empty() {
+        return Support::new()
+            .supported()
+            .iter()
+            .map(ToString::to_string)
+            .chain(options.plugins.iter().map(|plugin| plugin.name.clone()))
+            .filter(|name| config.languages.contains(name))
+            .filter_map(|language| {
+                let tasks: Vec<Task> =
+                    named_tasks(&language, Path::new("."), config, &options.plugins)?;
+                let (inputs, fixtures) = inputs(&language, Path::new("."), options);
+                Some(Target {
+                    language,
+                    project: None,
+                    tasks,
+                    inputs,
+                    fixtures,
+                })
+            })
+            .collect();
+    }
+    let mut targets: Vec<Target> = Vec::new();
+    for project in projects {
+        for language in &project.languages {
+            let Some(mut tasks) = named_tasks(language, &project.path, config, &options.plugins)
+            else {
+                continue;
+            };
+            for task in &mut tasks {
+                task.directory = Some(
+                    task.directory
+                        .as_ref()
+                        .map_or_else(|| project.path.clone(), |dir| project.path.join(dir)),
+                );
             }
-            8 => {
-                ret.lint = if report_error {
-                    CODE_NOT_VALID.to_uppercase()
-                } else {
-                    CODE_VALID.to_uppercase()
+            let (inputs, fixtures) = inputs(language, &project.path, options);
+            targets.push(Target {
+                language: language.clone(),
+                project: Some(project.clone()),
+                tasks,
+                inputs,
+                fixtures,
+            });
+        }
+    }
+    targets
+}
+
+///
+/// # Inputs
+///
+/// Get the files of a language in a project directory: its sources, manifests, lockfiles and tool
+/// configurations, then the other files of its test and fixture directories.
+///
+/// Any other file, like a README, never invalidates the cached results. None without cache.
+///
+fn inputs(language: &str, directory: &Path, options: &Options) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    if options.no_cache {
+        return (Vec::new(), Vec::new());
+    }
+    let builtin: Option<Language> = language.parse::<Language>().ok();
+    let plugin: Option<&Plugin> = options.plugins.iter().find(|p| p.name.eq(language));
+    let (inputs, others): (Vec<PathBuf>, Vec<PathBuf>) = tree(directory).partition(|path| {
+        builtin.is_some_and(|l| l.matches(path) || l.configured(path))
+            || plugin.is_some_and(|p| p.matches(path))
+    });
+    (
+        inputs,
+        others
+            .into_iter()
+            .filter(|path| fixture(path.strip_prefix(directory).unwrap_or(path)))
+            .collect(),
+    )
+}
+
+#[doc = "Get the tasks of a built-in or plugin language in a project directory by its tux.toml name"]
+fn named_tasks(
+    name: &str,
+    directory: &Path,
+    config: &Config,
+    plugins: &[Plugin],
+) -> Option<Vec<Task>> {
+    if let Ok(language) = name.parse::<Language>() {
+        return Some(config.merge(name, language.project_tasks(directory)));
+    }
+    plugins
+        .iter()
+        .find(|plugin| plugin.name.eq(name))
+        .map(|plugin| config.merge(&plugin.name, plugin.tasks()))
+}
+
+#[doc = "Check if a project directory contains one of the paths"]
+fn owns(project: &Project, paths: &[PathBuf]) -> bool {
+    let (Ok(current), Ok(root)) = (std::env::current_dir(), project.path.canonicalize()) else {
+        return false;
+    };
+    let current: PathBuf = current.canonicalize().unwrap_or(current);
+    paths
+        .iter()
+        .any(|path| current.join(path).starts_with(&root))
+}
+
+///
+/// # Parallel
+///
+/// Check up to `jobs` languages at the same time, each one drawing on its own lines
+///
+fn check_in_parallel(targets: &[Target], checkup: &Checkup, jobs: usize) -> Result<Vec<Report>> {
+    let mut offsets: Vec<usize> = Vec::new();
+    let mut line: usize = 0;
+    for target in targets {
+        offsets.push(line);
+        line += target.tasks.len() + 3;
+    }
+    clear(None)?;
+    in_parallel(targets, jobs, |index, target| {
+        let offset: usize = offsets[index];
+        title(target.title().as_str(), offset)
+            .and_then(|()| source_code_verify(target, checkup, Some(offset + 1)))
+    })
+    .into_iter()
+    .collect()
+}
+
+///
+/// # In parallel
+///
+/// Run a job per item on up to `jobs` threads, taking the items in order.
+///
+/// The results keep the order of the items, whichever job finishes first. No item is taken
+/// once the checkup is interrupted.
+///
+fn in_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    job: impl Fn(usize, &T) -> R + Sync,
+) -> Vec<R> {
+    let next: AtomicUsize = AtomicUsize::new(0);
+    let results: Mutex<Vec<(usize, R)>> = Mutex::new(Vec::new());
+    scope(|s| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            s.spawn(|| loop {
+                if interrupted() {
+                    break;
                 }
-            }
-            _ => {}
+                let index: usize = next.fetch_add(1, Ordering::SeqCst);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result: R = job(index, item);
+                results
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((index, result));
+            });
         }
-        if strict && report_error.eq(&true) {
-            ret.code = FAILURE;
-            assert!(waiting(
-                (
-                    format!("Exiting {} (strict mode): {}/9.", command.0, index + 1),
-                    format!("Exiting the {l} test"),
-                    format!("Exiting the {l} test"),
-                ),
-                Tux::new("sleep").arg("10"),
-                waiting_line
-            )
-            .is_ok());
-            assert!(execute!(stdout(), Clear(ClearType::All), Hide).is_ok());
-            break;
+    });
+    let mut results: Vec<(usize, R)> = results.into_inner().unwrap_or_else(PoisonError::into_inner);
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[doc = "Display a message for a while, or print it once in plain mode"]
+fn notice(data: (String, String, String), line: usize) -> Result<()> {
+    if plain() {
+        return title(data.0.as_str(), line);
+    }
+    if interrupted() {
+        return Ok(());
+    }
+    waiting(data, Tux::new("sleep").arg("10"), line, None).map(|_| ())
+}
+
+#[doc = "Clear the screen between two languages when they are checked one after another"]
+fn clear(offset: Option<usize>) -> Result<()> {
+    if offset.is_none() && !plain() {
+        execute!(stdout(), Clear(ClearType::All)).map_err(Error::Terminal)?;
+    }
+    Ok(())
+}
+
+fn source_code_verify(target: &Target, checkup: &Checkup, offset: Option<usize>) -> Result<Report> {
+    let l: String = target.title();
+    let todo: &[Task] = &target.tasks;
+    let first_line: usize = offset.unwrap_or(0);
+    let total: usize = todo.len();
+    let mut runner: TaskRunner = TaskRunner::new();
+    if checkup.cache {
+        runner = runner.cache(
+            Cache::default()
+                .inputs(&target.inputs)
+                .fixtures(&target.fixtures),
+        );
+    }
+    let results: Vec<TaskResult> = runner
+        .logs(target.logs())
+        .selection(checkup.selection.clone())
+        .renderer(Arc::clone(&checkup.renderer))
+        .strict(checkup.strict)
+        .fast_first(checkup.fast_first)
+        .line(first_line)
+        .run_tasks(todo)?;
+    let done: usize = results.len();
+    let mut ret: Report = Report::new();
+    ret.language.clone_from(&target.language);
+    if let Some(project) = &target.project {
+        ret.project = project.name();
+    }
+    for result in results {
+        ret.add(result);
+    }
+    for task in &todo[done..] {
+        ret.add(if checkup.selection.enabled(task) {
+            TaskResult::not_run(task, &target.logs())
+        } else {
+            TaskResult::skipped(task, &target.logs())
+        });
+    }
+    for category in Category::all() {
+        if !todo.iter().any(|task| task.category.eq(&category)) {
+            ret.skip(category);
         }
     }
-    if results.contains(&true) {
-        ret.code = FAILURE;
-        assert!(waiting(
-            (
-                format!("Exit code failure for {l} test"),
-                format!("Exiting the {l} test"),
-                format!("Exiting the {l} test"),
-            ),
-            Tux::new("sleep").arg("10"),
-            waiting_line + 1
-        )
-        .is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-        Ok(ret)
-    } else {
-        ret.code = SUCCESS;
-        assert!(waiting(
+    let failed: bool = ret.status().failed();
+    if failed && checkup.strict && !interrupted() {
+        notice(
             (
-                format!("Exit code success for {l} test"),
+                format!(
+                    "Exiting {} (strict mode): {done}/{total}.",
+                    todo[done - 1].description,
+                ),
                 format!("Exiting the {l} test"),
                 format!("Exiting the {l} test"),
             ),
-            Tux::new("sleep").arg("10"),
-            waiting_line + 1
-        )
-        .is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-        Ok(ret)
+            first_line + done - 1,
+        )?;
+        clear(offset)?;
+        if !plain() {
+            execute!(stdout(), Hide).map_err(Error::Terminal)?;
+        }
+    }
+    ret.code = if failed { FAILURE } else { SUCCESS };
+    let outcome: &str = if failed { "failure" } else { "success" };
+    notice(
+        (
+            format!("Exit code {outcome} for {l} test"),
+            format!("Exiting the {l} test"),
+            format!("Exiting the {l} test"),
+        ),
+        first_line + done,
+    )?;
+    clear(offset)?;
+    ret.summarize();
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{thread::sleep, time::Duration};
+
+    #[doc = "Run the jobs sleeping longer for the first items, and get their order and peak concurrency"]
+    fn scheduled(jobs: usize) -> (Vec<usize>, usize) {
+        let running: AtomicUsize = AtomicUsize::new(0);
+        let peak: AtomicUsize = AtomicUsize::new(0);
+        let items: Vec<u64> = vec![40, 30, 20, 10, 0];
+        let order: Vec<usize> = in_parallel(&items, jobs, |index, delay| {
+            let now: usize = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            sleep(Duration::from_millis(*delay));
+            running.fetch_sub(1, Ordering::SeqCst);
+            index
+        });
+        (order, peak.into_inner())
+    }
+
+    #[test]
+    fn parallel_reports_keep_the_language_order() {
+        let (order, peak) = scheduled(3);
+        assert_eq!(order, [0, 1, 2, 3, 4]);
+        assert!(peak > 1 && peak <= 3);
+    }
+
+    #[test]
+    fn one_job_runs_the_languages_one_after_another() {
+        let (order, peak) = scheduled(1);
+        assert_eq!(order, [0, 1, 2, 3, 4]);
+        assert_eq!(peak, 1);
+        assert!(in_parallel(&[] as &[u64], 4, |index, _| index).is_empty());
+    }
+
+    #[test]
+    fn invalid_config_exits_with_4() {
+        let parse: Error = Error::ConfigParse {
+            line: 6,
+            column: 11,
+            message: String::from("invalid type"),
+        };
+        let unknown: Error = Error::UnknownLanguage {
+            name: String::from("cobol"),
+        };
+        assert_eq!(exit_code(&parse), ExitCode::from(4));
+        assert_eq!(exit_code(&unknown), ExitCode::from(4));
+        assert_eq!(exit_code(&Error::ConfigMissing), ExitCode::from(3));
     }
 }
@@ -0,0 +1,340 @@
+use crate::ask::TaskStatus;
+use crate::error::Result;
+use crate::task::{Category, Task, TaskResult};
+use crate::tool::{resolve, task_program, version};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs::{copy, create_dir_all, read, read_to_string, write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::UNIX_EPOCH,
+};
+
+#[doc = "The directory of the cached results"]
+pub const CACHE: &str = "zuu/.cache";
+
+#[derive(Clone, Debug)]
+#[doc = "The results of the tasks, reused while their inputs are unchanged"]
+pub struct Cache {
+    directory: PathBuf,
+    inputs: Vec<u8>,
+    fixtures: Vec<u8>,
+    versions: Arc<Mutex<BTreeMap<(String, PathBuf), String>>>,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self::new(CACHE)
+    }
+}
+
+impl Cache {
+    #[must_use]
+    #[doc = "A cache without input files, storing its results in the directory"]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            inputs: Vec::new(),
+            fixtures: Vec::new(),
+            versions: Arc::default(),
+        }
+    }
+
+    ///
+    /// # Inputs
+    ///
+    /// Hash the files read by every task, like the sources, the lockfiles and the tool
+    /// configurations.
+    ///
+    /// A file which can't be read is hashed by its path only.
+    ///
+    #[must_use]
+    pub fn inputs(mut self, files: &[PathBuf]) -> Self {
+        self.inputs = digest(files);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Hash the files only read by the tests, like their fixtures"]
+    pub fn fixtures(mut self, files: &[PathBuf]) -> Self {
+        self.fixtures = digest(files);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Check if the result of a task can be reused, audits and outdated checks depend on remote data"]
+    pub fn cacheable(task: &Task) -> bool {
+        !matches!(task.category, Category::Audit | Category::Outdated)
+    }
+
+    ///
+    /// # Key
+    ///
+    /// Hash the inputs of a task with SHA-256: the input files, the fixtures for the tests and
+    /// the custom tasks, the command line with its directory and environment, the log directory
+    /// and the program running the command.
+    ///
+    /// The program is identified by its path and the output of `--version` in the task directory,
+    /// so a tool upgrade behind a proxy like rustup or a shim like nvm reruns its tasks.
+    ///
+    #[must_use]
+    pub fn key(&self, task: &Task, logs: &Path) -> String {
+        let mut hasher: Sha256 = Sha256::new();
+        let directory: String = task
+            .directory
+            .as_ref()
+            .map(|directory| directory.display().to_string())
+            .unwrap_or_default();
+        let identity: String = self
+            .identity(task)
+            .map(|(path, version)| format!("{}\n{version}", path.display()))
+            .unwrap_or_default();
+        feed(&mut hasher, env!("CARGO_PKG_VERSION").as_bytes());
+        feed(&mut hasher, &self.inputs);
+        if matches!(task.category, Category::Test | Category::Custom) {
+            feed(&mut hasher, &self.fixtures);
+        }
+        feed(&mut hasher, task.name.as_bytes());
+        feed(&mut hasher, task.category.name().as_bytes());
+        feed(&mut hasher, task.command.as_bytes());
+        feed(&mut hasher, &[u8::from(task.shell)]);
+        feed(&mut hasher, directory.as_bytes());
+        for (name, value) in &task.env {
+            feed(&mut hasher, name.as_bytes());
+            feed(&mut hasher, value.as_bytes());
+        }
+        feed(&mut hasher, logs.display().to_string().as_bytes());
+        feed(&mut hasher, identity.as_bytes());
+        hex(&hasher.finalize()[..16])
+    }
+
+    #[doc = "Get the path of a cached result"]
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{key}.json"))
+    }
+
+    #[doc = "Get the directory of the logs of a cached result"]
+    fn logs(&self, key: &str) -> PathBuf {
+        self.directory.join(key)
+    }
+
+    #[must_use]
+    #[doc = "Get the cached result of a key, with the logs of the run which produced it"]
+    pub fn load(&self, key: &str) -> Option<TaskResult> {
+        let content: String = read_to_string(self.path(key)).ok()?;
+        let result: TaskResult = serde_json::from_str(content.as_str()).ok()?;
+        if !result.stdout.is_file() || !result.stderr.is_file() {
+            return None;
+        }
+        Some(TaskResult {
+            cached: true,
+            duration: 0.0,
+            ..result
+        })
+    }
+
+    ///
+    /// # Store
+    ///
+    /// Save the result of a task which passed or failed, not one killed by its timeout or by
+    /// Ctrl-C, nor one whose program is not installed or crashed.
+    ///
+    /// The logs are copied next to the result, so a later run with other inputs can't replace
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// `Io` when the cache can't be written
+    ///
+    pub fn store(&self, key: &str, result: &TaskResult) -> Result<()> {
+        if !matches!(result.status, TaskStatus::Pass | TaskStatus::Fail) {
+            return Ok(());
+        }
+        let logs: PathBuf = self.logs(key);
+        create_dir_all(&logs)?;
+        let stdout: PathBuf = logs.join("stdout.txt");
+        let stderr: PathBuf = logs.join("stderr.txt");
+        copy(&result.stdout, &stdout)?;
+        copy(&result.stderr, &stderr)?;
+        let stored: TaskResult = TaskResult {
+            stdout,
+            stderr,
+            ..result.clone()
+        };
+        let content: String =
+            serde_json::to_string(&stored).map_err(|e| std::io::Error::other(e.to_string()))?;
+        Ok(write(self.path(key), content)?)
+    }
+
+    ///
+    /// # Identity
+    ///
+    /// Identify the program of a task by its path and its version, asked once per directory.
+    ///
+    /// A program given by its path, like a project script, is never run to get a version, its
+    /// size and modification time are used instead.
+    ///
+    fn identity(&self, task: &Task) -> Option<(PathBuf, String)> {
+        let program: String = task_program(task)?;
+        let directory: PathBuf = task.directory.clone().unwrap_or_else(|| PathBuf::from("."));
+        let path: PathBuf = resolve(program.as_str(), &directory)?;
+        if program.contains('/') {
+            return Some((path.clone(), modified(&path)));
+        }
+        let mut versions = self.versions.lock().ok()?;
+        let version: &String =
+            versions
+                .entry((program, directory))
+                .or_insert_with_key(|(program, directory)| {
+                    version(program, directory).unwrap_or_else(|| modified(&path))
+                });
+        Some((path.clone(), version.clone()))
+    }
+}
+
+#[doc = "Describe a file by its size and modification time"]
+fn modified(path: &Path) -> String {
+    let Ok(metadata) = path.metadata() else {
+        return String::new();
+    };
+    let modified: u128 = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_nanos());
+    format!("{} {modified}", metadata.len())
+}
+
+#[doc = "Hash a field, prefixed by its length so two fields never read as one"]
+fn feed(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+#[doc = "Hash files by their sorted paths and contents"]
+fn digest(files: &[PathBuf]) -> Vec<u8> {
+    let mut sorted: Vec<&PathBuf> = files.iter().collect();
+    sorted.sort();
+    let mut hasher: Sha256 = Sha256::new();
+    for file in sorted {
+        feed(&mut hasher, file.display().to_string().as_bytes());
+        feed(&mut hasher, &read(file).unwrap_or_default());
+    }
+    hasher.finalize().to_vec()
+}
+
+#[doc = "Write bytes in lowercase hexadecimal"]
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::Exit;
+    use std::{fs, process};
+
+    #[doc = "A cache in a temporary directory, with a source file and the logs of a run"]
+    fn setup(name: &str) -> (PathBuf, Cache, PathBuf) {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-cache-{name}-{}", process::id()));
+        let logs: PathBuf = root.join("zuu");
+        fs::create_dir_all(logs.join("stdout")).unwrap();
+        fs::create_dir_all(logs.join("stderr")).unwrap();
+        let source: PathBuf = root.join("main.rs");
+        fs::write(&source, "fn main() {}\n").unwrap();
+        let cache: Cache = Cache::new(root.join("cache")).inputs(&[source]);
+        (root, cache, logs)
+    }
+
+    #[doc = "The result of a task exiting with the code, its logs written"]
+    fn result(task: &Task, code: Option<i32>, timed_out: bool, logs: &Path) -> TaskResult {
+        let exit: Exit = Exit {
+            code,
+            timed_out,
+            ..Exit::default()
+        };
+        let result: TaskResult = TaskResult::new(task, &exit, logs);
+        fs::write(&result.stdout, "ok\n").unwrap();
+        fs::write(&result.stderr, "").unwrap();
+        result
+    }
+
+    #[test]
+    fn unchanged_inputs_hit_the_cache() {
+        let (root, cache, logs) = setup("hit");
+        let task: Task = Task::new("test", Category::Test, "true");
+        let key: String = cache.key(&task, &logs);
+        cache
+            .store(&key, &result(&task, Some(0), false, &logs))
+            .unwrap();
+        fs::write(
+            logs.join("stdout").join(task.output_file()),
+            "another run\n",
+        )
+        .unwrap();
+        let loaded: Option<TaskResult> = cache.load(&cache.key(&task, &logs));
+        let stdout: Option<String> = loaded
+            .as_ref()
+            .and_then(|loaded| fs::read_to_string(&loaded.stdout).ok());
+        fs::remove_dir_all(&root).unwrap();
+        let loaded: TaskResult = loaded.unwrap();
+        assert!(loaded.cached);
+        assert_eq!(loaded.status, TaskStatus::Pass);
+        assert_eq!(stdout.as_deref(), Some("ok\n"));
+    }
+
+    #[test]
+    fn changed_inputs_miss_the_cache() {
+        let (root, cache, logs) = setup("miss");
+        let task: Task = Task::new("test", Category::Test, "true");
+        let key: String = cache.key(&task, &logs);
+        cache
+            .store(&key, &result(&task, Some(1), false, &logs))
+            .unwrap();
+        fs::write(root.join("main.rs"), "fn main() { todo!() }\n").unwrap();
+        let changed: Cache = cache.clone().inputs(&[root.join("main.rs")]);
+        let fixtures: Cache = cache.clone().fixtures(&[root.join("main.rs")]);
+        let lint: Task = Task::new("lint", Category::Lint, "true");
+        let hit: bool = cache.load(&key).is_some();
+        let missed: bool = changed.load(&changed.key(&task, &logs)).is_none();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(hit);
+        assert!(missed);
+        assert_ne!(fixtures.key(&task, &logs), key);
+        assert_eq!(fixtures.key(&lint, &logs), cache.key(&lint, &logs));
+    }
+
+    #[test]
+    fn remote_checks_are_not_cacheable() {
+        assert!(!Cache::cacheable(&Task::new(
+            "audit",
+            Category::Audit,
+            "true"
+        )));
+        assert!(!Cache::cacheable(&Task::new(
+            "outdated",
+            Category::Outdated,
+            "true"
+        )));
+        assert!(Cache::cacheable(&Task::new("test", Category::Test, "true")));
+    }
+
+    #[test]
+    fn only_passed_and_failed_tasks_are_stored() {
+        let (root, cache, logs) = setup("store");
+        let task: Task = Task::new("test", Category::Test, "true");
+        let key: String = cache.key(&task, &logs);
+        let timeout: TaskResult = result(&task, None, true, &logs);
+        cache.store(&key, &timeout).unwrap();
+        let timed_out: bool = cache.load(&key).is_none();
+        cache
+            .store(&key, &TaskResult::error(&task, "crashed", &logs))
+            .unwrap();
+        let errored: bool = cache.load(&key).is_none();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(timeout.status, TaskStatus::Timeout);
+        assert!(timed_out);
+        assert!(errored);
+    }
+}
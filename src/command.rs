@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+
+#[doc = "The characters with a meaning for the shell when they are not quoted"]
+pub const OPERATORS: [char; 11] = ['|', '&', ';', '<', '>', '(', ')', '$', '`', '*', '?'];
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+#[doc = "A command of tux.toml, a command line or the list of its arguments"]
+pub enum CommandLine {
+    Line(String),
+    Argv(Vec<String>),
+}
+
+impl CommandLine {
+    #[must_use]
+    #[doc = "Get the command line, the arguments being quoted when needed"]
+    pub fn line(&self) -> String {
+        match self {
+            Self::Line(line) => line.clone(),
+            Self::Argv(argv) => join(argv),
+        }
+    }
+}
+
+#[doc = "The words of a command line, with the first shell operator found outside the quotes"]
+struct Words {
+    words: Vec<String>,
+    operator: Option<char>,
+}
+
+#[doc = "Split a command line like sh, without any expansion"]
+fn words(line: &str) -> Result<Words, String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut operator: Option<char> = None;
+    let mut word: Option<String> = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let current: &mut String = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(quoted) => current.push(quoted),
+                        None => return Err(String::from("unterminated single quote")),
+                    }
+                }
+            }
+            '"' => {
+                let current: &mut String = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped @ ('"' | '\\' | '$' | '`')) => current.push(escaped),
+                            Some(other) => {
+                                current.push('\\');
+                                current.push(other);
+                            }
+                            None => return Err(String::from("unterminated double quote")),
+                        },
+                        Some(quoted @ ('$' | '`')) => {
+                            operator = operator.or(Some(quoted));
+                            current.push(quoted);
+                        }
+                        Some(quoted) => current.push(quoted),
+                        None => return Err(String::from("unterminated double quote")),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some(escaped) => word.get_or_insert_with(String::new).push(escaped),
+                None => return Err(String::from("trailing backslash")),
+            },
+            c if c.is_whitespace() => {
+                if let Some(done) = word.take() {
+                    words.push(done);
+                }
+            }
+            '#' if word.is_none() => {
+                operator = operator.or(Some('#'));
+                break;
+            }
+            '~' if word.is_none() => {
+                operator = operator.or(Some('~'));
+                word = Some(String::from("~"));
+            }
+            c => {
+                if OPERATORS.contains(&c) {
+                    operator = operator.or(Some(c));
+                }
+                word.get_or_insert_with(String::new).push(c);
+            }
+        }
+    }
+    if let Some(done) = word {
+        words.push(done);
+    }
+    Ok(Words { words, operator })
+}
+
+///
+/// # Split
+///
+/// Split a command line in arguments, honoring the quotes and the backslashes like sh.
+///
+/// # Errors
+///
+/// On an empty line, an unterminated quote, or when the line needs a shell: pipes, redirections,
+/// lists, substitutions, globs, comments, `~` or variable assignments
+///
+pub fn split(line: &str) -> Result<Vec<String>, String> {
+    let words: Words = words(line)?;
+    if let Some(operator) = words.operator {
+        return Err(format!("`{operator}` needs a shell, set shell = true"));
+    }
+    match words.words.first() {
+        None => Err(String::from("the command is empty")),
+        Some(first) if assignment(first) => Err(format!(
+            "`{first}` needs a shell, set the variable in env or set shell = true"
+        )),
+        Some(_) => Ok(words.words),
+    }
+}
+
+#[doc = "Check if a word sets a variable, like `CI=1`"]
+fn assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c.eq(&'_'))
+    })
+}
+
+#[must_use]
+#[doc = "Check if a command line can only run through a shell"]
+pub fn needs_shell(line: &str) -> bool {
+    split(line).is_err()
+}
+
+#[must_use]
+#[doc = "Quote an argument for sh when it contains special characters"]
+pub fn quote(arg: &str) -> String {
+    let plain: bool = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=,+@%".contains(c));
+    if plain {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[must_use]
+#[doc = "Join arguments in a command line which splits back to them"]
+pub fn join(argv: &[String]) -> String {
+    argv.iter()
+        .map(|arg| quote(arg))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(words: &[&str]) -> Vec<String> {
+        words.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn split_plain_commands() {
+        let cases: [(&str, &[&str]); 9] = [
+            ("cargo test", &["cargo", "test"]),
+            ("  cargo   test  ", &["cargo", "test"]),
+            ("echo 'a b' c", &["echo", "a b", "c"]),
+            ("echo \"a b\"", &["echo", "a b"]),
+            ("echo 'it''s'", &["echo", "its"]),
+            ("echo \"a \\\"b\\\" \\\\ \\n\"", &["echo", "a \"b\" \\ \\n"]),
+            ("echo a\\ b", &["echo", "a b"]),
+            ("echo '$HOME' '*' '#'", &["echo", "$HOME", "*", "#"]),
+            ("grep a=b file~ x#y", &["grep", "a=b", "file~", "x#y"]),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(split(line), Ok(argv(expected)), "{line}");
+        }
+    }
+
+    #[test]
+    fn split_refuses_shell_lines() {
+        let cases: [&str; 14] = [
+            "",
+            "   ",
+            "a | b",
+            "a && b",
+            "a; b",
+            "a > out",
+            "echo $HOME",
+            "echo \"$HOME\"",
+            "echo \"`id`\"",
+            "ls *.rs",
+            "ls ~/src",
+            "cargo test # all",
+            "CI=1 cargo test",
+            "echo 'unterminated",
+        ];
+        for line in cases {
+            assert!(split(line).is_err(), "{line}");
+            assert!(needs_shell(line), "{line}");
+        }
+    }
+
+    #[test]
+    fn argv_round_trips_through_join() {
+        let cases: [&[&str]; 6] = [
+            &["cargo", "test"],
+            &["./my script.sh", "a b"],
+            &["echo", "it's", "\"quoted\""],
+            &["echo", "$HOME", "*", "a|b", "~", "#"],
+            &["grep", "CI=1", ""],
+            &["printf", "a\\nb", "`id`"],
+        ];
+        for words in cases {
+            let line: String = CommandLine::Argv(argv(words)).line();
+            assert_eq!(split(line.as_str()), Ok(argv(words)), "{line}");
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+};
+
+#[doc = "The errors returned by zuu"]
+#[derive(Debug)]
+pub enum Error {
+    #[doc = "No tux.toml in the current directory"]
+    ConfigMissing,
+    #[doc = "The tux.toml is not valid, the position starts at 1"]
+    ConfigParse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[doc = "tux init --yes found no language to write in tux.toml"]
+    NothingDetected,
+    #[doc = "A language of tux.toml is neither supported nor provided by a plugin"]
+    UnknownLanguage { name: String },
+    #[doc = "A plugin could not be loaded"]
+    Plugin { path: PathBuf, message: String },
+    #[doc = "A shell task comes from a tux.toml which is not trusted"]
+    Untrusted { task: String },
+    #[doc = "A task command can't run without a shell"]
+    Command { task: String, message: String },
+    #[doc = "Tasks gave no verdict: program missing, not started or killed by a signal"]
+    TaskError { tasks: Vec<String> },
+    #[doc = "A task program could not be started"]
+    SpawnFailed { program: String },
+    #[doc = "A task exited with a failure, the code is `None` when killed by a signal"]
+    NonZeroExit { code: Option<i32> },
+    #[doc = "A task was killed after its timeout"]
+    Timeout,
+    #[doc = "The checkup was stopped by Ctrl-C"]
+    Interrupted,
+    #[doc = "Drawing on the terminal failed"]
+    Terminal(std::io::Error),
+    #[doc = "A file or a directory could not be read or written"]
+    Io(std::io::Error),
+}
+
+#[doc = "The result of the zuu functions"]
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    ///
+    /// # Config parse
+    ///
+    /// Locate a toml error in the configuration source
+    ///
+    #[must_use]
+    pub fn config_parse(source: &str, error: &toml::de::Error) -> Self {
+        let offset: usize = error.span().map_or(0, |span| span.start);
+        Self::config_at(source, offset, error.message().to_string())
+    }
+
+    #[must_use]
+    #[doc = "An error of the configuration source at a byte offset, located by its line and column"]
+    pub fn config_at(source: &str, offset: usize, message: String) -> Self {
+        let before: &str = source.get(..offset).unwrap_or(source);
+        let line: usize = before.matches('\n').count() + 1;
+        let column: usize = before
+            .rsplit_once('\n')
+            .map_or(before, |(_, last)| last)
+            .chars()
+            .count()
+            + 1;
+        Self::ConfigParse {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConfigMissing => write!(f, "Failed to load config => run tux init"),
+            Self::ConfigParse {
+                line,
+                column,
+                message,
+            } => write!(f, "tux.toml:{line}:{column}: {message}"),
+            Self::NothingDetected => write!(
+                f,
+                "No language detected in the current directory => run tux init to select them"
+            ),
+            Self::UnknownLanguage { name } => write!(
+                f,
+                "tux.toml: unknown language {name} => expected one of {} or a plugin name",
+                crate::support::Support::new().all().join(", ")
+            ),
+            Self::Plugin { path, message } => write!(f, "{}: {message}", path.display()),
+            Self::Untrusted { task } => write!(
+                f,
+                "{task} runs through a shell and tux.toml is not trusted => review it and run tux trust"
+            ),
+            Self::Command { task, message } => write!(f, "{task}: {message}"),
+            Self::TaskError { tasks } => write!(
+                f,
+                "{} could not run => see the logs and tux doctor",
+                tasks.join(", ")
+            ),
+            Self::SpawnFailed { program } => write!(f, "Failed to start {program}"),
+            Self::NonZeroExit { code: Some(code) } => write!(f, "Command failed with code {code}"),
+            Self::NonZeroExit { code: None } => write!(f, "Command killed by a signal"),
+            Self::Timeout => write!(f, "Command timed out"),
+            Self::Interrupted => write!(f, "Checkup interrupted"),
+            Self::Terminal(e) => write!(f, "Terminal failure: {e}"),
+            Self::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Terminal(e) | Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
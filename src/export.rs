@@ -0,0 +1,342 @@
+use crate::ask::{Report, TaskStatus};
+use crate::error::Result;
+use serde_json::{json, Value};
+use std::{fmt::Write, fs::File, io::Write as IoWrite, path::Path};
+
+#[doc = "The machine-readable report formats"]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Json,
+    Junit,
+    Sarif,
+}
+
+impl Format {
+    #[must_use]
+    #[doc = "Find a format by its command line name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Self::Json),
+            "junit" => Some(Self::Junit),
+            "sarif" => Some(Self::Sarif),
+            _ => None,
+        }
+    }
+}
+
+///
+/// # Render
+///
+/// Serialize the reports in the requested format
+///
+#[must_use]
+pub fn render(reports: &[Report], format: Format) -> String {
+    match format {
+        Format::Json => json(reports),
+        Format::Junit => junit(reports),
+        Format::Sarif => sarif(reports),
+    }
+}
+
+///
+/// # Export
+///
+/// Write the reports in the requested format to a file
+///
+/// # Errors
+///
+/// `Io` on no write rights
+///
+pub fn export(reports: &[Report], format: Format, output: &Path) -> Result<()> {
+    let mut file = File::create(output)?;
+    file.write_all(render(reports, format).as_bytes())?;
+    Ok(file.sync_all()?)
+}
+
+#[doc = "Serialize the reports as a json array"]
+fn json(reports: &[Report]) -> String {
+    serde_json::to_string_pretty(reports).unwrap_or_else(|_| String::from("[]"))
+}
+
+#[doc = "Escape a string for an xml attribute or text node"]
+fn escape(data: &str) -> String {
+    data.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[doc = "Count the tasks of a report having one of the statuses"]
+fn count(report: &Report, statuses: &[TaskStatus]) -> usize {
+    report
+        .tasks
+        .iter()
+        .filter(|t| statuses.contains(&t.status))
+        .count()
+}
+
+#[doc = "Count the failed tasks of a report, killed by their timeout included"]
+fn failed(report: &Report) -> usize {
+    count(report, &[TaskStatus::Fail, TaskStatus::Timeout])
+}
+
+#[doc = "Count the tasks of a report which gave no verdict"]
+fn errored(report: &Report) -> usize {
+    count(report, &[TaskStatus::Error])
+}
+
+#[doc = "Count the tasks of a report which did not run"]
+fn ignored(report: &Report) -> usize {
+    count(report, &[TaskStatus::Skipped, TaskStatus::NotRun])
+}
+
+#[doc = "Serialize the reports as a junit xml document, one test suite per report row"]
+fn junit(reports: &[Report]) -> String {
+    let tests: usize = reports.iter().map(|r| r.tasks.len()).sum();
+    let failures: usize = reports.iter().map(failed).sum();
+    let errors: usize = reports.iter().map(errored).sum();
+    let skipped: usize = reports.iter().map(ignored).sum();
+    let time: f64 = reports
+        .iter()
+        .flat_map(|r| &r.tasks)
+        .fold(0.0_f64, |sum, t| sum + t.duration);
+    let mut xml: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="tux" tests="{tests}" failures="{failures}" errors="{errors}" skipped="{skipped}" time="{time:.3}">"#
+    );
+    for report in reports {
+        let failures: usize = failed(report);
+        let errors: usize = errored(report);
+        let skipped: usize = ignored(report);
+        let time: f64 = report.tasks.iter().fold(0.0_f64, |sum, t| sum + t.duration);
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{failures}" errors="{errors}" skipped="{skipped}" time="{time:.3}">"#,
+            escape(&report.name()),
+            report.tasks.len(),
+        );
+        for task in &report.tasks {
+            let _ = writeln!(
+                xml,
+                r#"    <testcase classname="{}" name="{}" time="{:.3}">"#,
+                escape(&report.name()),
+                escape(&task.description),
+                task.duration,
+            );
+            if task.status.eq(&TaskStatus::Skipped) {
+                xml.push_str("      <skipped/>\n");
+            } else if task.status.eq(&TaskStatus::NotRun) {
+                xml.push_str("      <skipped message=\"not run\"/>\n");
+            } else if task.status.failed() {
+                let element: &str = if task.status.eq(&TaskStatus::Error) {
+                    "error"
+                } else {
+                    "failure"
+                };
+                let _ = writeln!(
+                    xml,
+                    r#"      <{element} message="{}" type="{}">{}{}</{element}>"#,
+                    escape(&task.command),
+                    if task.timed_out {
+                        "timeout".to_string()
+                    } else {
+                        task.category.to_string()
+                    },
+                    escape(
+                        &task
+                            .tail
+                            .iter()
+                            .map(|line| format!("{line}\n"))
+                            .collect::<String>()
+                    ),
+                    escape(&task.stderr.display().to_string()),
+                );
+            }
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}</system-out>",
+                escape(&task.stdout.display().to_string())
+            );
+            let _ = writeln!(
+                xml,
+                "      <system-err>{}</system-err>",
+                escape(&task.stderr.display().to_string())
+            );
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+#[doc = "Serialize the failed tasks as a sarif 2.1.0 log"]
+fn sarif(reports: &[Report]) -> String {
+    let mut rules: Vec<Value> = Vec::new();
+    let mut results: Vec<Value> = Vec::new();
+    for report in reports {
+        for task in &report.tasks {
+            let id: String = format!("{}/{}", report.name(), task.name);
+            rules.push(json!({
+                "id": id,
+                "name": task.name,
+                "shortDescription": { "text": task.description },
+                "properties": { "category": task.category, "command": task.command },
+            }));
+            if !task.status.failed() {
+                continue;
+            }
+            results.push(json!({
+                "ruleId": id,
+                "properties": {
+                    "code": task.code,
+                    "signal": task.signal,
+                    "duration": task.duration,
+                    "tail": task.tail,
+                    "status": task.status,
+                },
+                "level": "error",
+                "message": { "text": format!(
+                    "{}: {} {}",
+                    report.name(),
+                    task.command,
+                    match task.status {
+                        TaskStatus::Timeout => "timed out",
+                        TaskStatus::Error => "could not run",
+                        _ => "failed",
+                    }
+                ) },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": task.stderr.display().to_string() }
+                    }
+                }],
+            }));
+        }
+    }
+    let log: Value = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "tux",
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::{Category, Exit, Task, TaskResult};
+    use std::path::Path;
+
+    #[doc = "The logs of the tasks, never written"]
+    const LOGS: &str = "target/zuu-export-none";
+
+    #[doc = "A task exiting with the code, or killed by its timeout"]
+    fn exited(name: &str, code: Option<i32>, timed_out: bool) -> TaskResult {
+        let exit: Exit = Exit {
+            code,
+            timed_out,
+            ..Exit::default()
+        };
+        TaskResult::new(
+            &Task::new(name, Category::Test, name),
+            &exit,
+            Path::new(LOGS),
+        )
+    }
+
+    #[doc = "A report with a passed, failed, errored, skipped and timed out task"]
+    fn report() -> Report {
+        let logs: &Path = Path::new(LOGS);
+        let mut report: Report = Report::new();
+        report.language = String::from("Rust");
+        report.add(exited("pass", Some(0), false));
+        report.add(exited("fail", Some(1), false));
+        report.add(TaskResult::error(
+            &Task::new("error", Category::Lint, "error"),
+            "not started",
+            logs,
+        ));
+        report.add(TaskResult::skipped(
+            &Task::new("skipped", Category::Doc, "skipped"),
+            logs,
+        ));
+        report.add(exited("timeout", None, true));
+        report
+    }
+
+    #[test]
+    fn json_keeps_every_status() {
+        let value: Value = serde_json::from_str(&render(&[report()], Format::Json)).unwrap();
+        let statuses: Vec<&str> = value[0]["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|task| task["status"].as_str())
+            .collect();
+        assert_eq!(statuses, ["pass", "fail", "error", "skipped", "timeout"]);
+    }
+
+    #[test]
+    fn junit_counts_and_marks_every_status() {
+        let xml: String = render(&[report()], Format::Junit);
+        assert!(xml.contains(
+            r#"<testsuite name="Rust" tests="5" failures="2" errors="1" skipped="1" time="0.000">"#
+        ));
+        assert!(xml.contains(r#"<failure message="fail" type="test">"#));
+        assert!(xml.contains(r#"<failure message="timeout" type="timeout">"#));
+        assert!(xml.contains(r#"<error message="error" type="lint">not started"#));
+        assert!(xml.contains("<skipped/>"));
+        assert_eq!(xml.matches("<testcase ").count(), 5);
+    }
+
+    #[test]
+    fn junit_escapes_the_markup() {
+        let mut report: Report = Report::new();
+        report.language = String::from("C++");
+        report.project = String::from("a&b");
+        let task: Task = Task::new("<cmp>", Category::Test, r#"test "a" < 'b'"#);
+        report.add(TaskResult::error(&task, "x > y & z", Path::new(LOGS)));
+        let xml: String = render(&[report], Format::Junit);
+        assert!(xml.contains(r#"<testsuite name="a&amp;b/C++""#));
+        assert!(xml.contains(r#"name="&lt;cmp&gt;""#));
+        assert!(xml.contains(r#"message="test &quot;a&quot; &lt; &apos;b&apos;""#));
+        assert!(xml.contains("x &gt; y &amp; z"));
+        assert!(!xml.contains("<cmp>"));
+    }
+
+    #[test]
+    fn sarif_reports_only_the_failures() {
+        let log: Value = serde_json::from_str(&render(&[report()], Format::Sarif)).unwrap();
+        let run: &Value = &log["runs"][0];
+        let rules: usize = run["tool"]["driver"]["rules"].as_array().unwrap().len();
+        let messages: Vec<&str> = run["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|result| result["message"]["text"].as_str())
+            .collect();
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(rules, 5);
+        assert_eq!(
+            messages,
+            [
+                "Rust: fail failed",
+                "Rust: error could not run",
+                "Rust: timeout timed out"
+            ]
+        );
+    }
+}
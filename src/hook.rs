@@ -0,0 +1,303 @@
+use crate::error::{Error, Result};
+use std::{
+    fs::{read_to_string, remove_file, rename, File},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+#[doc = "The git hooks tux can install"]
+pub const HOOKS: [&str; 2] = ["pre-commit", "pre-push"];
+
+#[doc = "The first line of the tux block in a hook script"]
+pub const BEGIN: &str = "# >>> tux >>>";
+
+#[doc = "The last line of the tux block in a hook script"]
+pub const END: &str = "# <<< tux <<<";
+
+#[doc = "The suffix of an existing hook moved aside, run by the tux hook once tux passed"]
+pub const CHAINED: &str = "tux-chained";
+
+#[doc = "The shells able to run the tux block inside an existing hook"]
+pub const SHELLS: [&str; 3] = ["sh", "bash", "dash"];
+
+#[doc = "Get the command of the tux block of a hook, running tux in strict and plain mode"]
+fn tux(hook: &str) -> String {
+    let staged: &str = if hook.eq("pre-commit") {
+        " --staged"
+    } else {
+        ""
+    };
+    format!("tux strict --plain{staged} || exit 1")
+}
+
+#[doc = "Get the tux block of a hook, running tux in strict and plain mode"]
+#[must_use]
+pub fn block(hook: &str) -> String {
+    format!("{BEGIN}\n{}\n{END}\n", tux(hook))
+}
+
+#[doc = "Get the sh hook running tux, then the existing hook moved aside, with the same arguments and input"]
+#[must_use]
+pub fn wrapper(hook: &str) -> String {
+    format!(
+        "#!/bin/sh\n{BEGIN}\n{}\nexec \"$(dirname \"$0\")/{hook}.{CHAINED}\" \"$@\"\n{END}\n",
+        tux(hook)
+    )
+}
+
+#[must_use]
+#[doc = "Check if a hook runs with sh or bash, a script without any shebang being run by sh"]
+pub fn shell(script: &str) -> bool {
+    let Some(shebang) = script
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("#!"))
+    else {
+        return true;
+    };
+    let mut words = shebang.split_whitespace();
+    let program: Option<&str> = words
+        .next()
+        .and_then(|word| Path::new(word).file_name()?.to_str());
+    let interpreter: Option<&str> = if program.eq(&Some("env")) {
+        words.find(|word| !word.starts_with('-'))
+    } else {
+        program
+    };
+    interpreter.is_some_and(|name| SHELLS.contains(&name))
+}
+
+#[doc = "Get the path of an existing hook moved aside by tux"]
+fn chained(path: &Path, hook: &str) -> PathBuf {
+    path.with_file_name(format!("{hook}.{CHAINED}"))
+}
+
+///
+/// # Path
+///
+/// Get the path of a hook, honoring `core.hooksPath`
+///
+/// # Errors
+///
+/// `SpawnFailed` when git is missing, `NonZeroExit` when the current directory is not a repository
+///
+pub fn path(hook: &str) -> Result<PathBuf> {
+    let output: Output = git(&["rev-parse", "--git-path", "hooks"])?;
+    let hooks: String = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(PathBuf::from(hooks).join(hook))
+}
+
+///
+/// # Install
+///
+/// Write the hook, or chain tux before the commands of an existing hook.
+///
+/// A sh or bash hook gets the tux block after its shebang. Any other hook, like a python one, is
+/// moved to `<hook>.tux-chained` and run by a new sh hook once tux passed.
+///
+/// # Errors
+///
+/// On git or write failure
+///
+pub fn install(hook: &str) -> Result<()> {
+    install_at(&path(hook)?, hook)
+}
+
+#[doc = "Install the tux block of a hook in the script at a path, see `install`"]
+fn install_at(path: &Path, hook: &str) -> Result<()> {
+    let script: String = match read_to_string(path) {
+        Ok(existing) if existing.contains(BEGIN) => return Ok(()),
+        Ok(existing) if existing.trim().is_empty() => format!("#!/bin/sh\n{}", block(hook)),
+        Ok(existing) if shell(existing.as_str()) => match existing.strip_prefix("#!") {
+            Some(_) => {
+                let (shebang, body) = existing.split_once('\n').unwrap_or((existing.as_str(), ""));
+                format!("{shebang}\n{}{body}", block(hook))
+            }
+            None => format!("{}{existing}", block(hook)),
+        },
+        Err(e) if e.kind().eq(&ErrorKind::NotFound) => format!("#!/bin/sh\n{}", block(hook)),
+        _ => {
+            rename(path, chained(path, hook))?;
+            wrapper(hook)
+        }
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(path)?;
+    file.write_all(script.as_bytes())?;
+    file.sync_all()?;
+    Ok(executable(path)?)
+}
+
+///
+/// # Uninstall
+///
+/// Remove the tux block of a hook, and the hook itself when nothing else remains.
+///
+/// A hook moved aside by `install` is restored.
+///
+/// # Errors
+///
+/// On git or write failure
+///
+pub fn uninstall(hook: &str) -> Result<()> {
+    uninstall_at(&path(hook)?, hook)
+}
+
+#[doc = "Remove the tux block of a hook from the script at a path, see `uninstall`"]
+fn uninstall_at(path: &Path, hook: &str) -> Result<()> {
+    let Ok(existing) = read_to_string(path) else {
+        return Ok(());
+    };
+    let (Some(begin), Some(end)) = (existing.find(BEGIN), existing.find(END)) else {
+        return Ok(());
+    };
+    let script: String = format!(
+        "{}{}",
+        &existing[..begin],
+        existing[end + END.len()..].trim_start_matches('\n')
+    );
+    if script
+        .lines()
+        .all(|l| l.trim().is_empty() || l.starts_with("#!"))
+    {
+        remove_file(path)?;
+        let moved: PathBuf = chained(path, hook);
+        if moved.is_file() {
+            rename(moved, path)?;
+        }
+        return Ok(());
+    }
+    let mut file = File::create(path)?;
+    file.write_all(script.as_bytes())?;
+    Ok(file.sync_all()?)
+}
+
+///
+/// # Staged
+///
+/// Get the files added, copied, modified or renamed in the git index
+///
+/// # Errors
+///
+/// `SpawnFailed` when git is missing, `NonZeroExit` when the current directory is not a repository
+///
+pub fn staged() -> Result<Vec<PathBuf>> {
+    let output: Output = git(&["diff", "--cached", "--name-only", "--diff-filter=ACMR"])?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(PathBuf::from)
+        .collect())
+}
+
+#[doc = "Run git, failing on a non zero exit code"]
+fn git(args: &[&str]) -> Result<Output> {
+    let Ok(output) = Command::new("git").args(args).output() else {
+        return Err(Error::SpawnFailed {
+            program: String::from("git"),
+        });
+    };
+    if !output.status.success() {
+        return Err(Error::NonZeroExit {
+            code: output.status.code(),
+        });
+    }
+    Ok(output)
+}
+
+#[cfg(unix)]
+#[doc = "Make the hook executable"]
+fn executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+#[doc = "Make the hook executable"]
+fn executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, process};
+
+    #[doc = "A hooks directory with the hook script, none when empty"]
+    fn hooks(name: &str, script: &str) -> (PathBuf, PathBuf) {
+        let dir: PathBuf = PathBuf::from(format!("target/zuu-hook-{name}-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path: PathBuf = dir.join("pre-commit");
+        if !script.is_empty() {
+            fs::write(&path, script).unwrap();
+        }
+        (dir, path)
+    }
+
+    #[test]
+    fn other_hooks_are_chained_and_restored() {
+        let python: &str = "#!/usr/bin/env python3\nprint('lint')\n";
+        let (dir, path) = hooks("chained", python);
+        install_at(&path, "pre-commit").unwrap();
+        let installed: String = fs::read_to_string(&path).unwrap();
+        let moved: String = fs::read_to_string(dir.join("pre-commit.tux-chained")).unwrap();
+        uninstall_at(&path, "pre-commit").unwrap();
+        let restored: String = fs::read_to_string(&path).unwrap();
+        let left: bool = dir.join("pre-commit.tux-chained").exists();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(installed, wrapper("pre-commit"));
+        assert_eq!(moved, python);
+        assert_eq!(restored, python);
+        assert!(!left);
+    }
+
+    #[test]
+    fn shell_hooks_keep_their_commands() {
+        let script: &str = "#!/bin/bash -e\nmake check\n";
+        let (dir, path) = hooks("shell", script);
+        install_at(&path, "pre-commit").unwrap();
+        let installed: String = fs::read_to_string(&path).unwrap();
+        uninstall_at(&path, "pre-commit").unwrap();
+        let restored: String = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            installed,
+            format!("#!/bin/bash -e\n{}make check\n", block("pre-commit"))
+        );
+        assert_eq!(restored, script);
+    }
+
+    #[test]
+    fn installing_twice_writes_one_block() {
+        let (dir, path) = hooks("twice", "");
+        install_at(&path, "pre-commit").unwrap();
+        install_at(&path, "pre-commit").unwrap();
+        let installed: String = fs::read_to_string(&path).unwrap();
+        uninstall_at(&path, "pre-commit").unwrap();
+        let removed: bool = !path.exists();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(installed, format!("#!/bin/sh\n{}", block("pre-commit")));
+        assert_eq!(installed.matches(BEGIN).count(), 1);
+        assert!(removed);
+    }
+
+    #[test]
+    fn shell_hooks() {
+        let cases: [(&str, bool); 9] = [
+            ("#!/bin/sh\nmake\n", true),
+            ("#!/bin/bash -e\nmake\n", true),
+            ("#!/usr/bin/env bash\nmake\n", true),
+            ("#!/usr/bin/env -S bash -e\nmake\n", true),
+            ("make\n", true),
+            ("#!/usr/bin/env python3\nprint()\n", false),
+            ("#!/usr/bin/node\n", false),
+            ("#!/usr/bin/perl -w\n", false),
+            ("#!/bin/zsh\n", false),
+        ];
+        for (script, expected) in cases {
+            assert_eq!(shell(script), expected, "{script}");
+        }
+    }
+}
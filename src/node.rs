@@ -0,0 +1,664 @@
+use crate::command::quote;
+use crate::support::walk;
+use crate::task::Task;
+use serde_json::Value;
+use std::{
+    fs::{canonicalize, read_to_string},
+    path::{Path, PathBuf},
+};
+
+#[doc = "The file name of a TypeScript project"]
+pub const TSCONFIG: &str = "tsconfig.json";
+
+#[doc = "The name of the built-in TypeScript type-checking task, replaced by one task per root tsconfig"]
+pub const TYPES: &str = "types";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[doc = "A tsconfig found in a project, with the tsconfigs it references"]
+pub struct TsConfig {
+    #[doc = "The path of the tsconfig, relative to the project directory"]
+    pub path: PathBuf,
+    pub references: Vec<PathBuf>,
+}
+
+impl TsConfig {
+    ///
+    /// # Command
+    ///
+    /// Type-check the project with the tsc of `node_modules`, never downloaded by npx.
+    ///
+    /// A solution, having references, is checked with `tsc --build`, which builds its references
+    /// in dependency order so their declarations exist, any other project with `tsc --noEmit`.
+    ///
+    #[must_use]
+    pub fn command(&self) -> String {
+        let path: String = quote(self.path.display().to_string().as_str());
+        if !self.references.is_empty() {
+            return format!("npx --no --offline tsc --build {path}");
+        }
+        format!("npx --no --offline tsc --noEmit -p {path}")
+    }
+
+    ///
+    /// # Name
+    ///
+    /// Get the name of the type-checking task, `types` for the tsconfig of the project directory,
+    /// else named after its directory, like `types-packages-api`.
+    ///
+    #[must_use]
+    pub fn name(&self) -> String {
+        let parts: Vec<&str> = self
+            .path
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .filter_map(|c| c.as_os_str().to_str())
+            .collect();
+        if parts.is_empty() {
+            return TYPES.to_string();
+        }
+        format!("{TYPES}-{}", parts.join("-"))
+    }
+}
+
+///
+/// # Strip
+///
+/// Turn the JSON with comments and trailing commas accepted by tsc into plain JSON
+///
+#[must_use]
+pub fn strip(source: &str) -> String {
+    let mut json: String = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut string: bool = false;
+    while let Some(c) = chars.next() {
+        if string {
+            json.push(c);
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        json.push(escaped);
+                    }
+                }
+                '"' => string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                string = true;
+                json.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|next| next.ne(&'\n')).is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous: char = ' ';
+                for next in chars.by_ref() {
+                    if previous.eq(&'*') && next.eq(&'/') {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            (']' | '}', _) => {
+                let end: usize = json.trim_end().len();
+                if json[..end].ends_with(',') {
+                    json.truncate(end - 1);
+                }
+                json.push(c);
+            }
+            _ => json.push(c),
+        }
+    }
+    json
+}
+
+#[doc = "Read a tsconfig of a project directory"]
+fn read(directory: &Path, path: &Path) -> Option<Value> {
+    let source: String = read_to_string(directory.join(path)).ok()?;
+    serde_json::from_str(strip(source.as_str()).as_str()).ok()
+}
+
+#[doc = "Get the path of a tsconfig relative to the project directory, without any `..`"]
+fn normalize(directory: &Path, path: PathBuf) -> PathBuf {
+    canonicalize(directory)
+        .ok()
+        .zip(canonicalize(directory.join(&path)).ok())
+        .and_then(|(root, full)| full.strip_prefix(root).ok().map(Path::to_path_buf))
+        .unwrap_or(path)
+}
+
+#[doc = "Read the referenced tsconfigs of a tsconfig, a referenced directory meaning its tsconfig.json"]
+fn references(directory: &Path, path: &Path) -> Vec<PathBuf> {
+    let Some(config) = read(directory, path) else {
+        return Vec::new();
+    };
+    let parent: &Path = path.parent().unwrap_or_else(|| Path::new(""));
+    config
+        .get("references")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|reference| reference.get("path").and_then(Value::as_str))
+        .map(|reference| {
+            let referenced: PathBuf = parent.join(reference);
+            if directory.join(&referenced).is_dir() {
+                return normalize(directory, referenced.join(TSCONFIG));
+            }
+            normalize(directory, referenced)
+        })
+        .collect()
+}
+
+///
+/// # Tsconfigs
+///
+/// Find the root tsconfigs of a project, honoring the `.gitignore` rules and skipping `node_modules`.
+///
+/// A tsconfig referenced by another one is checked through it, so it is not a root.
+///
+#[must_use]
+pub fn tsconfigs(directory: &Path) -> Vec<TsConfig> {
+    let mut configs: Vec<TsConfig> = walk(directory)
+        .filter(|path| path.file_name().is_some_and(|name| name.eq(TSCONFIG)))
+        .filter_map(|path| path.strip_prefix(directory).ok().map(Path::to_path_buf))
+        .map(|path| TsConfig {
+            references: references(directory, &path),
+            path,
+        })
+        .collect();
+    configs.sort_by(|a, b| {
+        (a.path.components().count(), &a.path).cmp(&(b.path.components().count(), &b.path))
+    });
+    let referenced: Vec<PathBuf> = configs
+        .iter()
+        .flat_map(|config| &config.references)
+        .filter_map(|path| canonicalize(directory.join(path)).ok())
+        .collect();
+    configs
+        .into_iter()
+        .filter(|config| {
+            canonicalize(directory.join(&config.path)).is_ok_and(|path| !referenced.contains(&path))
+        })
+        .collect()
+}
+
+///
+/// # Typecheck
+///
+/// Replace the `types` task by one task per root tsconfig of the project, named by
+/// `TsConfig::name`. Without any tsconfig the task is kept as it is.
+///
+#[must_use]
+pub fn typecheck(directory: &Path, tasks: Vec<Task>) -> Vec<Task> {
+    let configs: Vec<TsConfig> = tsconfigs(directory);
+    if configs.is_empty() {
+        return tasks;
+    }
+    let mut expanded: Vec<Task> = Vec::new();
+    for task in tasks {
+        if task.name.ne(TYPES) {
+            expanded.push(task);
+            continue;
+        }
+        for config in &configs {
+            expanded.push(Task {
+                description: format!("{} ({})", task.description, config.path.display()),
+                command: config.command(),
+                name: config.name(),
+                ..task.clone()
+            });
+        }
+    }
+    expanded
+}
+
+#[doc = "The lockfiles of the JavaScript package managers, checked in this order"]
+pub const LOCKFILES: [(&str, PackageManager); 6] = [
+    ("pnpm-lock.yaml", PackageManager::Pnpm),
+    ("yarn.lock", PackageManager::Yarn),
+    ("bun.lock", PackageManager::Bun),
+    ("bun.lockb", PackageManager::Bun),
+    ("package-lock.json", PackageManager::Npm),
+    ("npm-shrinkwrap.json", PackageManager::Npm),
+];
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[doc = "The package manager installing the dependencies of a JavaScript project"]
+pub enum PackageManager {
+    #[default]
+    Npm,
+    #[doc = "Yarn 1, the classic one"]
+    Yarn,
+    #[doc = "Yarn 2 and later"]
+    Berry,
+    Pnpm,
+    Bun,
+}
+
+impl PackageManager {
+    #[must_use]
+    #[doc = "Get the program of the package manager"]
+    pub const fn program(self) -> &'static str {
+        match self {
+            Self::Npm => "npm",
+            Self::Yarn | Self::Berry => "yarn",
+            Self::Pnpm => "pnpm",
+            Self::Bun => "bun",
+        }
+    }
+
+    #[doc = "Get the package manager of a `packageManager` field, like `pnpm@9.1.0`"]
+    fn from_field(field: &str) -> Option<Self> {
+        let (name, version): (&str, &str) = field.split_once('@').unwrap_or((field, ""));
+        match name {
+            "npm" => Some(Self::Npm),
+            "yarn" if version.starts_with("0.") || version.starts_with("1.") => Some(Self::Yarn),
+            "yarn" => Some(Self::Berry),
+            "pnpm" => Some(Self::Pnpm),
+            "bun" => Some(Self::Bun),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the command running a tool installed in the project, never downloading it"]
+    pub const fn exec(self) -> &'static str {
+        match self {
+            Self::Npm => "npx --no --offline",
+            Self::Yarn => "yarn run",
+            Self::Berry => "yarn exec",
+            Self::Pnpm => "pnpm exec",
+            Self::Bun => "bun run",
+        }
+    }
+
+    ///
+    /// # Translate
+    ///
+    /// Get the command of the package manager equivalent to a npm or npx command of the built-in
+    /// tasks. The tools run with `exec`, yarn 2 having no `node_modules/.bin` with Plug'n'Play.
+    ///
+    /// With a lockfile, the installs never write it. `None` when the package manager has no
+    /// equivalent, like `prune` for yarn, and the command itself when it does not call npm.
+    ///
+    #[must_use]
+    pub fn translate(self, command: &str, locked: bool) -> Option<String> {
+        if let Some(tool) = command.strip_prefix("npx --no --offline ") {
+            return Some(format!("{} {tool}", self.exec()));
+        }
+        let Some(args) = command
+            .strip_prefix("npm ")
+            .or_else(|| command.eq("npm").then_some(""))
+        else {
+            return Some(command.to_string());
+        };
+        let (sub, rest): (&str, &str) = args.split_once(' ').unwrap_or((args, ""));
+        let translated: &str = match (self, sub) {
+            (_, "install") if !locked => return Some(format!("{} {args}", self.program())),
+            (Self::Npm, "install") => "npm ci",
+            (Self::Yarn, "install") => "yarn install --frozen-lockfile",
+            (Self::Berry, "install") => "yarn install --immutable",
+            (Self::Pnpm, "install") => "pnpm install --frozen-lockfile",
+            (Self::Bun, "install") => "bun install --frozen-lockfile",
+            (Self::Berry, "audit") => "yarn npm audit",
+            (Self::Bun, "test") => "bun run test",
+            (Self::Berry | Self::Bun, "outdated")
+            | (Self::Yarn | Self::Berry | Self::Bun, "prune") => return None,
+            (Self::Npm, _) => return Some(command.to_string()),
+            _ => return Some(format!("{} {args}", self.program())),
+        };
+        if rest.is_empty() {
+            return Some(translated.to_string());
+        }
+        Some(format!("{translated} {rest}"))
+    }
+}
+
+#[doc = "Read the `packageManager` field of the package.json of a directory"]
+fn field(directory: &Path) -> Option<PackageManager> {
+    let source: String = read_to_string(directory.join("package.json")).ok()?;
+    let manifest: Value = serde_json::from_str(source.as_str()).ok()?;
+    PackageManager::from_field(manifest.get("packageManager")?.as_str()?)
+}
+
+#[doc = "Get the package manager of the lockfile of a directory, yarn 2 writing a `__metadata` entry"]
+fn lockfile(directory: &Path) -> Option<PackageManager> {
+    let (name, manager): &(&str, PackageManager) = LOCKFILES
+        .iter()
+        .find(|(name, _)| directory.join(name).is_file())?;
+    if manager.eq(&PackageManager::Yarn)
+        && (directory.join(".yarnrc.yml").is_file()
+            || read_to_string(directory.join(name)).is_ok_and(|lock| lock.contains("__metadata:")))
+    {
+        return Some(PackageManager::Berry);
+    }
+    Some(*manager)
+}
+
+///
+/// # Package manager
+///
+/// Detect the package manager of a JavaScript project from the `packageManager` field of its
+/// package.json, or else from its lockfile.
+///
+/// The parent directories are searched up to the git root, so the packages of a workspace use the
+/// package manager of the workspace. npm is the default.
+///
+#[must_use]
+pub fn package_manager(directory: &Path) -> PackageManager {
+    search(directory).0
+}
+
+#[doc = "Check if a directory has a lockfile of the package manager"]
+fn locked(directory: &Path, manager: PackageManager) -> bool {
+    LOCKFILES.iter().any(|(name, owner)| {
+        owner.program().eq(manager.program()) && directory.join(name).is_file()
+    })
+}
+
+#[doc = "Find the package manager of a project, and if its own lockfile locks the dependencies"]
+fn search(directory: &Path) -> (PackageManager, bool) {
+    let start: PathBuf = canonicalize(directory).unwrap_or_else(|_| directory.to_path_buf());
+    for dir in start.ancestors() {
+        if let Some(manager) = field(dir) {
+            return (manager, locked(dir, manager));
+        }
+        if let Some(manager) = lockfile(dir) {
+            return (manager, true);
+        }
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+    (PackageManager::default(), false)
+}
+
+///
+/// # Manage
+///
+/// Run the npm commands of the tasks with the package manager of the project.
+///
+/// A task without any equivalent command is removed.
+///
+#[must_use]
+pub fn manage(directory: &Path, tasks: Vec<Task>) -> Vec<Task> {
+    let (manager, locked): (PackageManager, bool) = search(directory);
+    tasks
+        .into_iter()
+        .filter_map(|task| {
+            let command: String = manager.translate(task.command.as_str(), locked)?;
+            Some(Task { command, ..task })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, process};
+
+    #[test]
+    fn translate_installs() {
+        let cases: [(PackageManager, bool, &str); 7] = [
+            (PackageManager::Npm, true, "npm ci"),
+            (PackageManager::Npm, false, "npm install"),
+            (PackageManager::Yarn, true, "yarn install --frozen-lockfile"),
+            (PackageManager::Berry, true, "yarn install --immutable"),
+            (PackageManager::Pnpm, true, "pnpm install --frozen-lockfile"),
+            (PackageManager::Pnpm, false, "pnpm install"),
+            (PackageManager::Bun, true, "bun install --frozen-lockfile"),
+        ];
+        for (manager, locked, expected) in cases {
+            assert_eq!(
+                manager.translate("npm install", locked).as_deref(),
+                Some(expected),
+                "{manager:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn translate_commands() {
+        let cases: [(PackageManager, &str, Option<&str>); 10] = [
+            (PackageManager::Npm, "npm audit", Some("npm audit")),
+            (PackageManager::Yarn, "npm audit", Some("yarn audit")),
+            (PackageManager::Berry, "npm audit", Some("yarn npm audit")),
+            (PackageManager::Pnpm, "npm run lint", Some("pnpm run lint")),
+            (PackageManager::Bun, "npm test", Some("bun run test")),
+            (PackageManager::Berry, "npm outdated", None),
+            (PackageManager::Yarn, "npm prune", None),
+            (PackageManager::Pnpm, "npm prune", Some("pnpm prune")),
+            (PackageManager::Pnpm, "cargo test", Some("cargo test")),
+            (PackageManager::Npm, "npmx test", Some("npmx test")),
+        ];
+        for (manager, command, expected) in cases {
+            assert_eq!(
+                manager.translate(command, true).as_deref(),
+                expected,
+                "{manager:?} {command}"
+            );
+        }
+    }
+
+    #[test]
+    fn translate_tools() {
+        let cases: [(PackageManager, &str); 5] = [
+            (PackageManager::Npm, "npx --no --offline eslint ."),
+            (PackageManager::Yarn, "yarn run eslint ."),
+            (PackageManager::Berry, "yarn exec eslint ."),
+            (PackageManager::Pnpm, "pnpm exec eslint ."),
+            (PackageManager::Bun, "bun run eslint ."),
+        ];
+        for (manager, expected) in cases {
+            assert_eq!(
+                manager
+                    .translate("npx --no --offline eslint .", true)
+                    .as_deref(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[doc = "A project name, its files, the searched directory and the expected package manager"]
+    type Case = (
+        &'static str,
+        &'static [(&'static str, &'static str)],
+        &'static str,
+        (PackageManager, bool),
+    );
+
+    #[doc = "Create a project with files, in a git root so the search stops there"]
+    fn project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-node-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join(".git")).unwrap();
+        for (file, content) in files {
+            let path: PathBuf = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn search_package_managers() {
+        let cases: [Case; 7] = [
+            (
+                "none",
+                &[("package.json", "{}")],
+                "",
+                (PackageManager::Npm, false),
+            ),
+            (
+                "npm",
+                &[("package-lock.json", "{}")],
+                "",
+                (PackageManager::Npm, true),
+            ),
+            (
+                "yarn",
+                &[("yarn.lock", "a@1:\n")],
+                "",
+                (PackageManager::Yarn, true),
+            ),
+            (
+                "berry",
+                &[("yarn.lock", "__metadata:\n  version: 8\n")],
+                "",
+                (PackageManager::Berry, true),
+            ),
+            (
+                "field",
+                &[
+                    ("package.json", r#"{"packageManager":"pnpm@9.1.0"}"#),
+                    ("package-lock.json", "{}"),
+                ],
+                "",
+                (PackageManager::Pnpm, false),
+            ),
+            (
+                "locked",
+                &[
+                    ("package.json", r#"{"packageManager":"yarn@4.2.0"}"#),
+                    ("yarn.lock", "__metadata:\n"),
+                ],
+                "",
+                (PackageManager::Berry, true),
+            ),
+            (
+                "workspace",
+                &[("pnpm-lock.yaml", ""), ("packages/api/package.json", "{}")],
+                "packages/api",
+                (PackageManager::Pnpm, true),
+            ),
+        ];
+        for (name, files, sub, expected) in cases {
+            let root: PathBuf = project(name, files);
+            let found: (PackageManager, bool) = search(&root.join(sub));
+            fs::remove_dir_all(&root).unwrap();
+            assert_eq!(found, expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn strip_comments_and_trailing_commas() {
+        let source: &str = r#"{
+            // the compiler options
+            "compilerOptions": { "strict": true, /* inline */ },
+            "include": ["src/**/*", "a // not a comment", "b /* nor this */",],
+            "escaped": "a \" // quote",
+        }"#;
+        let json: Value = serde_json::from_str(strip(source).as_str()).unwrap();
+        assert_eq!(json["compilerOptions"]["strict"], Value::Bool(true));
+        assert_eq!(
+            json["include"],
+            serde_json::json!(["src/**/*", "a // not a comment", "b /* nor this */"])
+        );
+        assert_eq!(json["escaped"], Value::from("a \" // quote"));
+    }
+
+    #[test]
+    fn references_resolve_directories_and_files() {
+        let root: PathBuf = project(
+            "references",
+            &[
+                (
+                    "tsconfig.json",
+                    r#"{ "files": [], "references": [{ "path": "./packages/a" }, { "path": "packages/b/tsconfig.build.json" },] }"#,
+                ),
+                ("packages/a/tsconfig.json", "{}"),
+                (
+                    "packages/b/tsconfig.build.json",
+                    r#"{ "references": [{ "path": "../a" }] }"#,
+                ),
+            ],
+        );
+        let top: Vec<PathBuf> = references(&root, Path::new(TSCONFIG));
+        let nested: Vec<PathBuf> = references(&root, Path::new("packages/b/tsconfig.build.json"));
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            top,
+            [
+                PathBuf::from("packages/a/tsconfig.json"),
+                PathBuf::from("packages/b/tsconfig.build.json")
+            ]
+        );
+        assert_eq!(nested, [PathBuf::from("packages/a/tsconfig.json")]);
+    }
+
+    #[test]
+    fn tsconfigs_keep_the_roots_in_order() {
+        let root: PathBuf = project(
+            "roots",
+            &[
+                (
+                    "tsconfig.json",
+                    r#"{ "files": [], "references": [{ "path": "packages/api" }] }"#,
+                ),
+                (
+                    "packages/api/tsconfig.json",
+                    r#"{ "references": [{ "path": "../core" }] }"#,
+                ),
+                ("packages/core/tsconfig.json", "{}"),
+                ("tools/tsconfig.json", "{}"),
+                ("node_modules/dep/tsconfig.json", "{}"),
+            ],
+        );
+        let configs: Vec<TsConfig> = tsconfigs(&root);
+        fs::remove_dir_all(&root).unwrap();
+        let paths: Vec<&Path> = configs.iter().map(|c| c.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            [Path::new("tsconfig.json"), Path::new("tools/tsconfig.json")]
+        );
+        assert_eq!(
+            configs[0].command(),
+            "npx --no --offline tsc --build tsconfig.json"
+        );
+        assert_eq!(
+            configs[1].command(),
+            "npx --no --offline tsc --noEmit -p tools/tsconfig.json"
+        );
+        assert_eq!(configs[1].name(), "types-tools");
+    }
+
+    #[test]
+    fn tsconfigs_survive_reference_cycles() {
+        let root: PathBuf = project(
+            "cycle",
+            &[
+                ("tsconfig.json", r#"{ "references": [{ "path": "a" }] }"#),
+                (
+                    "a/tsconfig.json",
+                    r#"{ "references": [{ "path": "../b" }] }"#,
+                ),
+                (
+                    "b/tsconfig.json",
+                    r#"{ "references": [{ "path": "../a" }] }"#,
+                ),
+            ],
+        );
+        let configs: Vec<TsConfig> = tsconfigs(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].name(), TYPES);
+    }
+
+    #[test]
+    fn typecheck_replaces_the_types_task() {
+        let root: PathBuf = project(
+            "typecheck",
+            &[("tsconfig.json", "{}"), ("tools/tsconfig.json", "{}")],
+        );
+        let tasks: Vec<Task> = vec![
+            Task::new("test", crate::task::Category::Test, "npm test"),
+            Task::new(TYPES, crate::task::Category::Types, "npx tsc --noEmit"),
+        ];
+        let checked: Vec<Task> = typecheck(&root, tasks);
+        fs::remove_dir_all(&root).unwrap();
+        let names: Vec<&str> = checked.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["test", "types", "types-tools"]);
+    }
+}
@@ -0,0 +1,324 @@
+use crate::ask::{Report, TaskStatus};
+use crate::task::TaskResult;
+use crate::tool::which;
+use std::{
+    env,
+    ffi::OsString,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+#[doc = "The application name shown by the notification daemon"]
+pub const APP: &str = "tux";
+
+#[doc = "The program sending the notifications over D-Bus"]
+pub const NOTIFY_SEND: &str = "notify-send";
+
+#[doc = "The environment variable locating the session bus of the notification daemon"]
+pub const BUS: &str = "DBUS_SESSION_BUS_ADDRESS";
+
+#[doc = "How long a notification stays visible, in milliseconds"]
+pub const EXPIRE: i32 = 10000;
+
+#[derive(Clone, Debug)]
+#[doc = "Send the notifications with `notify-send`, or another program taking its arguments"]
+pub struct Notifier {
+    program: PathBuf,
+    bus: Option<OsString>,
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self {
+            program: PathBuf::from(NOTIFY_SEND),
+            bus: env::var_os(BUS).filter(|bus| !bus.is_empty()),
+        }
+    }
+}
+
+impl Notifier {
+    #[must_use]
+    #[doc = "Set the program sending the notifications, like a stub recording them"]
+    pub fn program(mut self, program: impl Into<PathBuf>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set the address of the session bus given to the program"]
+    pub fn bus(mut self, bus: impl Into<OsString>) -> Self {
+        self.bus = Some(bus.into());
+        self
+    }
+
+    ///
+    /// # Available
+    ///
+    /// Check if a session bus is set and the program is executable.
+    ///
+    /// Without them, notifications are dropped instead of failing the checkup, see `missing`.
+    ///
+    #[must_use]
+    pub fn available(&self) -> bool {
+        self.missing().is_none()
+    }
+
+    #[must_use]
+    #[doc = "Get the reason why the notifications can't be delivered, the missing bus or program"]
+    pub fn missing(&self) -> Option<String> {
+        if self.bus.is_none() {
+            return Some(format!("{BUS} is not set"));
+        }
+        if self.program.to_str().and_then(which).is_none() {
+            return Some(format!("{} is not installed", self.program.display()));
+        }
+        None
+    }
+
+    #[doc = "Send a notification, returns false when it could not be delivered"]
+    pub fn send(&self, summary: &str, body: &str, failed: bool) -> bool {
+        let Some(bus) = self.bus.as_ref().filter(|_| self.available()) else {
+            return false;
+        };
+        let icon: &str = if failed {
+            "dialog-error"
+        } else {
+            "dialog-information"
+        };
+        Command::new(&self.program)
+            .arg(format!("--app-name={APP}"))
+            .arg(format!("--icon={icon}"))
+            .arg(format!("--expire-time={EXPIRE}"))
+            .arg("--")
+            .arg(summary)
+            .arg(body)
+            .env(BUS, bus)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+}
+
+#[must_use]
+#[doc = "Check if the notifications of the session can be delivered"]
+pub fn available() -> bool {
+    Notifier::default().available()
+}
+
+#[doc = "Send a notification to the session, returns false when it could not be delivered"]
+pub fn send(summary: &str, body: &str, failed: bool) -> bool {
+    Notifier::default().send(summary, body, failed)
+}
+
+#[doc = "Check if a task ran and failed"]
+fn failed(task: &TaskResult) -> bool {
+    task.status.failed()
+}
+
+#[must_use]
+#[doc = "Get the pass and fail count of every report row, one per line"]
+pub fn counts(reports: &[Report]) -> String {
+    reports
+        .iter()
+        .map(|report| {
+            let passed: usize = report
+                .tasks
+                .iter()
+                .filter(|t| t.status.eq(&TaskStatus::Pass))
+                .count();
+            let failures: usize = report.tasks.iter().filter(|t| failed(t)).count();
+            format!("{}: {passed} passed, {failures} failed", report.name())
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+///
+/// # Regressions
+///
+/// Get the tasks which passed in the previous reports and fail in the new ones,
+/// as `language/task` names, prefixed by their project
+///
+#[must_use]
+pub fn regressions(previous: &[Report], reports: &[Report]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for report in reports {
+        let Some(old) = previous.iter().find(|r| r.name().eq(&report.name())) else {
+            continue;
+        };
+        for task in report.tasks.iter().filter(|t| failed(t)) {
+            if old
+                .tasks
+                .iter()
+                .any(|t| t.name.eq(&task.name) && t.status.eq(&TaskStatus::Pass))
+            {
+                names.push(format!("{}/{}", report.name(), task.name));
+            }
+        }
+    }
+    names
+}
+
+impl Notifier {
+    ///
+    /// # Finished
+    ///
+    /// Notify the end of a checkup with the counts of every language.
+    ///
+    /// The tasks which turned red, given by `regressions`, are named in the summary.
+    ///
+    pub fn finished(&self, reports: &[Report], red: &[String]) -> bool {
+        let failure: bool = reports.iter().flat_map(|r| &r.tasks).any(failed);
+        let summary: String = if !red.is_empty() {
+            format!("tux: {} turned red", red.join(", "))
+        } else if failure {
+            String::from("tux: checkup failed")
+        } else {
+            String::from("tux: checkup passed")
+        };
+        self.send(summary.as_str(), counts(reports).as_str(), failure)
+    }
+}
+
+#[doc = "Notify the session of the end of a checkup, see `Notifier::finished`"]
+pub fn finished(reports: &[Report], red: &[String]) -> bool {
+    Notifier::default().finished(reports, red)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::{Category, Exit, Task};
+    use std::{fs, path::Path, process};
+
+    #[doc = "A report row of a language with the tasks exiting with their codes"]
+    fn report(language: &str, tasks: &[(&str, i32)]) -> Report {
+        let mut report: Report = Report::new();
+        report.language = language.to_string();
+        for (name, code) in tasks {
+            let exit: Exit = Exit {
+                code: Some(*code),
+                ..Exit::default()
+            };
+            let task: Task = Task::new(name, Category::Test, "true");
+            report.add(TaskResult::new(
+                &task,
+                &exit,
+                Path::new("target/zuu-notify-none"),
+            ));
+        }
+        report
+    }
+
+    #[test]
+    fn counts_give_a_line_per_report() {
+        let mut api: Report = report("Rust", &[("test", 0), ("lint", 1), ("fmt", 0)]);
+        api.project = String::from("api");
+        let reports: Vec<Report> = vec![report("Go", &[("vet", 0)]), api];
+        assert_eq!(
+            counts(&reports),
+            "Go: 1 passed, 0 failed\napi/Rust: 2 passed, 1 failed"
+        );
+    }
+
+    #[test]
+    fn regressions_name_the_tasks_turning_red() {
+        let previous: Vec<Report> = vec![
+            report("Rust", &[("test", 0), ("lint", 1), ("fmt", 0)]),
+            report("Go", &[("vet", 0)]),
+        ];
+        let reports: Vec<Report> = vec![
+            report("Rust", &[("test", 1), ("lint", 1), ("fmt", 0)]),
+            report("Go", &[("vet", 0)]),
+            report("Python", &[("pytest", 1)]),
+        ];
+        assert_eq!(regressions(&previous, &reports), ["Rust/test"]);
+        assert!(regressions(&reports, &reports).is_empty());
+        assert!(regressions(&[], &reports).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[doc = "Write a stub of notify-send recording its arguments and bus, one per line"]
+    fn stub(name: &str, mode: u32) -> (PathBuf, PathBuf) {
+        use std::os::unix::fs::PermissionsExt;
+        let root: PathBuf = env::current_dir()
+            .unwrap()
+            .join(format!("target/zuu-notify-{name}-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let program: PathBuf = root.join("notify-send");
+        let log: PathBuf = root.join("calls");
+        let script: String = format!(
+            "#!/bin/sh\nfor arg in \"$@\"; do echo \"$arg\"; done > '{}'\necho \"${BUS}\" >> '{}'\n",
+            log.display(),
+            log.display()
+        );
+        fs::write(&program, script).unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(mode)).unwrap();
+        (program, log)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn finished_sends_the_summary_to_the_daemon() {
+        let (program, log) = stub("sent", 0o755);
+        let red: Vec<String> = vec![String::from("Rust/test")];
+        let sent: bool = Notifier::default()
+            .program(&program)
+            .bus("unix:path=/tmp/bus")
+            .finished(&[], &red);
+        let calls: String = fs::read_to_string(&log).unwrap_or_default();
+        fs::remove_dir_all(program.parent().unwrap()).unwrap();
+        assert!(sent);
+        assert_eq!(
+            calls.lines().collect::<Vec<&str>>(),
+            [
+                "--app-name=tux",
+                "--icon=dialog-information",
+                "--expire-time=10000",
+                "--",
+                "tux: Rust/test turned red",
+                "",
+                "unix:path=/tmp/bus",
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn not_executable_program_is_not_available() {
+        let (program, log) = stub("plain", 0o644);
+        let notifier: Notifier = Notifier::default()
+            .program(&program)
+            .bus("unix:path=/tmp/bus");
+        let sent: bool = notifier.send("summary", "body", true);
+        let called: bool = log.exists();
+        fs::remove_dir_all(program.parent().unwrap()).unwrap();
+        assert!(!notifier.available());
+        assert_eq!(
+            notifier.missing(),
+            Some(format!("{} is not installed", program.display()))
+        );
+        assert!(!sent);
+        assert!(!called);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn no_bus_sends_nothing() {
+        let (program, log) = stub("bus", 0o755);
+        let mut notifier: Notifier = Notifier::default().program(&program);
+        notifier.bus = None;
+        let missing: Option<String> = notifier.missing();
+        let sent: bool = notifier.send("summary", "body", false);
+        let called: bool = log.exists();
+        fs::remove_dir_all(program.parent().unwrap()).unwrap();
+        assert_eq!(
+            missing.as_deref(),
+            Some("DBUS_SESSION_BUS_ADDRESS is not set")
+        );
+        assert!(!sent);
+        assert!(!called);
+    }
+}
@@ -1,27 +1,181 @@
+use crate::error::{Error, Result};
+use crate::render::auto;
+use crate::task::{Category, Exit, Task, TaskResult};
 use crossterm::terminal::{Clear, ClearType};
 use crossterm::{
-    cursor::{Hide, MoveTo},
+    cursor::MoveTo,
     execute,
     style::{Color, Print, SetForegroundColor},
     terminal::size,
 };
 use std::{
-    io::{stdout, Error},
-    process::{Command, ExitCode},
+    io::{stdout, IsTerminal},
+    path::Path,
+    process::{Command, ExitCode, Stdio},
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Mutex, MutexGuard, PoisonError,
     },
-    thread::{self, sleep},
-    time::Duration,
+    thread::sleep,
+    time::{Duration, Instant},
 };
 
 #[doc = "The waiting task spinner strings"]
 pub const SPINNERS: [&str; 4] = [". ", "..", ".:", "::"];
 
+#[doc = "Serialize the terminal drawing between tasks running in parallel"]
+static TERMINAL: Mutex<()> = Mutex::new(());
+
+#[doc = "Lock the terminal for the time of a drawing"]
+pub fn terminal() -> MutexGuard<'static, ()> {
+    TERMINAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[doc = "Force the plain line-oriented output, printed on stderr to keep stdout for the report"]
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Force the plain output, without cursor control"]
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::SeqCst);
+}
+
+#[doc = "Hide the progress messages, only the report is printed"]
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Hide the progress messages"]
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::SeqCst);
+}
+
+#[doc = "Check if the progress messages are hidden"]
+#[must_use]
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::SeqCst)
+}
+
+#[doc = "Check if the output must be plain lines, when forced or when stdout is not a terminal"]
+#[must_use]
+pub fn plain() -> bool {
+    PLAIN.load(Ordering::SeqCst) || !stdout().is_terminal()
+}
+
+#[doc = "The delay between two checks of a running task"]
+pub const POLL: Duration = Duration::from_millis(50);
+
+#[doc = "The process groups of the running tasks, killed on interruption"]
+static CHILDREN: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[doc = "Set once the user asked to stop the checkup"]
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Check if the checkup has been interrupted"]
+#[must_use]
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+///
+/// # Interrupt
+///
+/// Stop the checkup, killing the process group of every running task
+///
+pub fn interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+    for pid in CHILDREN
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+    {
+        kill(*pid);
+    }
+}
+
+#[cfg(unix)]
+#[doc = "Kill the process group of a task"]
+pub(crate) fn kill(pid: u32) {
+    if let Ok(pid) = libc::pid_t::try_from(pid) {
+        // SAFETY: kill only sends a signal, the group id comes from a spawned child
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+#[doc = "Kill the process tree of a task"]
+pub(crate) fn kill(pid: u32) {
+    let _ = Command::new("taskkill")
+        .args(["/F", "/T", "/PID", pid.to_string().as_str()])
+        .status();
+}
+
+///
+/// # Run
+///
+/// Run a command in its own process group, killing the group when the timeout expires.
+///
+/// `progress` is called with the elapsed time while the command runs, its failures are ignored.
+///
+/// # Errors
+///
+/// `SpawnFailed` when the program can't be started, `Io` when it can't be waited
+///
+pub fn run(
+    cmd: &mut Command,
+    timeout: Option<Duration>,
+    progress: &mut dyn FnMut(Duration) -> Result<()>,
+) -> Result<Exit> {
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(cmd, 0);
+    let started: Instant = Instant::now();
+    let Ok(mut child) = cmd.stdin(Stdio::null()).spawn() else {
+        return Err(Error::SpawnFailed {
+            program: cmd.get_program().to_string_lossy().to_string(),
+        });
+    };
+    let pid: u32 = child.id();
+    CHILDREN
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(pid);
+    if interrupted() {
+        kill(pid);
+    }
+    let mut timed_out: bool = false;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Err(e) => break Err(Error::Io(e)),
+            Ok(None) => {}
+        }
+        if !timed_out && timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+            timed_out = true;
+            kill(pid);
+        }
+        let _ = progress(started.elapsed());
+        sleep(POLL);
+    };
+    CHILDREN
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|child| child.ne(&pid));
+    let status = status?;
+    #[cfg(unix)]
+    let signal: Option<i32> = std::os::unix::process::ExitStatusExt::signal(&status);
+    #[cfg(not(unix))]
+    let signal: Option<i32> = None;
+    Ok(Exit {
+        code: status.code(),
+        signal,
+        duration: started.elapsed(),
+        timed_out,
+        interrupted: interrupted(),
+    })
+}
+
 #[doc = "return the status code of the code checkup"]
 #[must_use]
-pub const fn zuu_exit(status: &Result<(), Error>) -> ExitCode {
+pub const fn zuu_exit(status: &Result<()>) -> ExitCode {
     if status.is_err() {
         return ExitCode::FAILURE;
     }
@@ -29,64 +183,58 @@ pub const fn zuu_exit(status: &Result<(), Error>) -> ExitCode {
 }
 
 ///
-/// # Ok
+/// # Status
 ///
-/// Print a success message
+/// Print a message with its colored status at the end of the line
 ///
-/// # Panics
+/// # Errors
 ///
 /// On fail to print the description
 ///
-/// # Errors
+fn status(description: &str, x: usize, color: Color, status: &str) -> Result<()> {
+    let (Ok((cols, _row)), Ok(y)) = (size(), u16::try_from(x)) else {
+        return Ok(());
+    };
+    let status_position: u16 = cols.saturating_sub(6);
+    execute!(
+        stdout(),
+        SetForegroundColor(color),
+        MoveTo(0, y),
+        Print("*"),
+        MoveTo(2, y),
+        SetForegroundColor(Color::White),
+        Print(description),
+        SetForegroundColor(Color::Blue),
+        MoveTo(status_position, y),
+        Print("["),
+        SetForegroundColor(color),
+        Print(format!(" {status} ")),
+        SetForegroundColor(Color::Blue),
+        Print("]"),
+        SetForegroundColor(Color::Reset),
+    )
+    .map_err(Error::Terminal)
+}
+
 ///
+/// # Ok
 ///
+/// Print a success message
 ///
-pub fn ok(description: &str, x: usize) {
-    if let Ok((cols, _rows)) = size() {
-        if let Ok(y) = u16::try_from(x) {
-            let status: &str = "[ ok ]";
-            if let Ok(len) = u16::try_from(status.len()) {
-                let status_position: u16 = cols.saturating_sub(len);
-                assert!(
-                    execute!(
-                        stdout(),
-                        SetForegroundColor(Color::Green),
-                        MoveTo(0, y),
-                        Print("*"),
-                        MoveTo(2, y),
-                        SetForegroundColor(Color::White),
-                        Print(description),
-                        MoveTo(status_position, 1),
-                        SetForegroundColor(Color::Blue),
-                        MoveTo(status_position, y),
-                        Print("["),
-                        SetForegroundColor(Color::Green),
-                        Print(" ok "),
-                        SetForegroundColor(Color::Blue),
-                        Print("]"),
-                        SetForegroundColor(Color::Reset),
-                    )
-                    .is_ok(),
-                    "Failed to print success message"
-                );
-            }
-        } else {
-            assert!(
-                execute!(
-                    stdout(),
-                    SetForegroundColor(Color::Green),
-                    MoveTo(0, 1),
-                    Print("*"),
-                    MoveTo(2, 1),
-                    SetForegroundColor(Color::White),
-                    Print(description),
-                    SetForegroundColor(Color::Reset),
-                )
-                .is_ok(),
-                "Failed to print success message"
-            );
-        }
+/// # Errors
+///
+/// On fail to print the description
+///
+pub fn ok(description: &str, x: usize) -> Result<()> {
+    if quiet() {
+        return Ok(());
+    }
+    let _terminal = terminal();
+    if plain() {
+        eprintln!("[ ok ] {description}");
+        return Ok(());
     }
+    status(description, x, Color::Green, "ok")
 }
 
 ///
@@ -94,59 +242,83 @@ pub fn ok(description: &str, x: usize) {
 ///
 /// Print an error message
 ///
-/// # Panics
+/// # Errors
 ///
 /// On fail to print the description
 ///
-pub fn ko(description: &str, x: usize) {
-    if let Ok((cols, _row)) = size() {
-        if let Ok(y) = u16::try_from(x) {
-            let status: &str = "[ !! ]";
-            if let Ok(len) = u16::try_from(status.len()) {
-                let status_position: u16 = cols.saturating_sub(len);
-                assert!(
-                    execute!(
-                        stdout(),
-                        SetForegroundColor(Color::Red),
-                        MoveTo(0, y),
-                        Print("*"),
-                        MoveTo(2, y),
-                        SetForegroundColor(Color::White),
-                        Print(description),
-                        SetForegroundColor(Color::Blue),
-                        MoveTo(status_position, y),
-                        Print("["),
-                        SetForegroundColor(Color::Red),
-                        Print(" !! "),
-                        SetForegroundColor(Color::Blue),
-                        Print("]"),
-                        SetForegroundColor(Color::Reset),
-                    )
-                    .is_ok(),
-                    "Failed to print error message"
-                );
-            }
-        }
-    } else {
-        assert!(
-            execute!(stdout(), Print(description)).is_ok(),
-            "Failed to print error message"
-        );
+pub fn ko(description: &str, x: usize) -> Result<()> {
+    if quiet() {
+        return Ok(());
+    }
+    let _terminal = terminal();
+    if plain() {
+        eprintln!("[ !! ] {description}");
+        return Ok(());
     }
+    status(description, x, Color::Red, "!!")
 }
+
 ///
+/// # Skip
 ///
-/// # Exec
+/// Print a disabled task message
 ///
-/// Execute the command writted in toml
+/// # Errors
 ///
-/// # Panics
+/// On fail to print the description
 ///
-/// On crossterm failure
+pub fn skip(description: &str, x: usize) -> Result<()> {
+    if quiet() {
+        return Ok(());
+    }
+    let _terminal = terminal();
+    if plain() {
+        eprintln!("[ -- ] {description}");
+        return Ok(());
+    }
+    status(description, x, Color::Yellow, "--")
+}
+
+///
+/// # Title
+///
+/// Print a section title, used to separate the languages checked in parallel
 ///
 /// # Errors
 ///
-/// On check failure
+/// On fail to print the title
+///
+pub fn title(description: &str, x: usize) -> Result<()> {
+    if quiet() {
+        return Ok(());
+    }
+    let _terminal = terminal();
+    if plain() {
+        eprintln!("==> {description}");
+        return Ok(());
+    }
+    let Ok(y) = u16::try_from(x) else {
+        return Ok(());
+    };
+    execute!(
+        stdout(),
+        MoveTo(0, y),
+        Clear(ClearType::CurrentLine),
+        SetForegroundColor(Color::Blue),
+        Print(description),
+        SetForegroundColor(Color::Reset),
+    )
+    .map_err(Error::Terminal)
+}
+
+///
+/// # Exec
+///
+/// Execute a command, showing its progress with the default renderer
+///
+/// # Errors
+///
+/// The error of `run`, or `Terminal` on crossterm failure
 ///
 pub fn waiting(
     data: (
@@ -156,80 +328,19 @@ pub fn waiting(
     ),
     cmd: &mut Command,
     x: usize,
-) -> std::io::Result<()> {
-    let spinner_done = Arc::new(AtomicBool::new(false));
-    let spinner_done_clone = Arc::clone(&spinner_done);
-
-    if let Ok((cols, _row)) = size() {
-        let status: &str = "   ";
-        if let Ok(len) = u16::try_from(status.len()) {
-            if let Ok(y) = u16::try_from(x) {
-                let status_position: u16 = cols.saturating_sub(len);
-                assert!(execute!(
-                    stdout(),
-                    MoveTo(0, y),
-                    SetForegroundColor(Color::Green),
-                    Print("*"),
-                    MoveTo(2, y),
-                    SetForegroundColor(Color::White),
-                    Print(data.0.to_string()),
-                    MoveTo(status_position, y),
-                    SetForegroundColor(Color::White),
-                    Print(" "),
-                    SetForegroundColor(Color::Reset),
-                )
-                .is_ok());
-
-                let spinner_thread = thread::spawn(move || {
-                    let mut output = stdout();
-                    while !spinner_done_clone.load(Ordering::SeqCst) {
-                        let status: &str = "[ :: ]";
-                        if let Ok(len) = u16::try_from(status.len()) {
-                            let status_position: u16 = cols.saturating_sub(len);
-                            for spin in SPINNERS {
-                                assert!(execute!(
-                                    output,
-                                    Hide,
-                                    SetForegroundColor(Color::Green),
-                                    MoveTo(0, y),
-                                    Print("*"),
-                                    MoveTo(2, y),
-                                    SetForegroundColor(Color::White),
-                                    Print(data.0.to_string()),
-                                    MoveTo(status_position, y),
-                                    SetForegroundColor(Color::Blue),
-                                    Print("["),
-                                    SetForegroundColor(Color::Green),
-                                    Print(format!(" {spin} ")),
-                                    SetForegroundColor(Color::Blue),
-                                    Print("]"),
-                                    SetForegroundColor(Color::Reset),
-                                )
-                                .is_ok());
-                                sleep(Duration::from_millis(500));
-                            }
-                        }
-                    }
-                });
-
-                let command_output = cmd.spawn()?.wait()?.success();
-
-                spinner_done.store(true, Ordering::SeqCst);
-                spinner_thread.join().unwrap();
-                assert!(
-                    crossterm::execute!(stdout(), MoveTo(0, y), Clear(ClearType::CurrentLine))
-                        .is_ok()
-                );
-
-                return if command_output {
-                    ok(data.1.as_str(), x);
-                    Ok(())
-                } else {
-                    ko(data.2.as_str(), x);
-                    Err(Error::new(std::io::ErrorKind::Other, "Command failed"))
-                };
-            }
-        }
-    }
-    Err(Error::new(std::io::ErrorKind::Other, "Error encountered"))
+    timeout: Option<Duration>,
+) -> Result<Exit> {
+    let task: Task = Task {
+        description: data.0,
+        success: data.1,
+        failure: data.2,
+        ..Task::new("waiting", Category::Custom, "")
+    };
+    let renderer = auto();
+    renderer.task_started(&task, x)?;
+    let exit: Exit = run(cmd, timeout, &mut |elapsed| {
+        renderer.task_progress(&task, x, elapsed)
+    })?;
+    renderer.task_finished(&task, &TaskResult::new(&task, &exit, Path::new("zuu")), x)?;
+    Ok(exit)
 }
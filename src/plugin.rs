@@ -0,0 +1,481 @@
+use crate::ask::TaskConfig;
+use crate::error::{Error, Result};
+use crate::output::{kill, POLL};
+use crate::support::{walk, Language};
+use crate::task::{Category, Provenance, Task};
+use crate::trust::config_home;
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fs::{read_dir, read_to_string},
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    thread::{sleep, spawn, JoinHandle},
+    time::{Duration, Instant},
+};
+
+#[doc = "The directory of the plugins, relative to the user configuration directory"]
+pub const PLUGINS: &str = "tux/plugins";
+
+#[doc = "The version of the JSON protocol spoken with the executable plugins"]
+pub const PROTOCOL: u32 = 1;
+
+#[doc = "How long an executable plugin may take to describe itself"]
+pub const DESCRIBE: Duration = Duration::from_secs(5);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[doc = "A task declared by a plugin, the category defaults to the one named like the task"]
+pub struct PluginTask {
+    pub name: String,
+    #[serde(flatten)]
+    pub config: TaskConfig,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[doc = "A language provider loaded from the plugin directory"]
+pub struct Plugin {
+    #[doc = "The language name used in tux.toml and in the report"]
+    pub name: String,
+    #[serde(default)]
+    #[doc = "The source file extensions, without the dot"]
+    pub extensions: Vec<String>,
+    #[serde(default)]
+    #[doc = "The project files rerunning the checks in watch mode, like manifests"]
+    pub files: Vec<String>,
+    #[serde(default)]
+    #[doc = "The files marking a project of the language for tux init"]
+    pub markers: Vec<String>,
+    #[serde(default)]
+    #[doc = "The tasks to run, in order"]
+    pub tasks: Vec<PluginTask>,
+}
+
+#[derive(Serialize)]
+#[doc = "The request written on the stdin of an executable plugin"]
+struct Request<'a> {
+    version: u32,
+    request: &'a str,
+    root: PathBuf,
+}
+
+impl Plugin {
+    ///
+    /// # Load
+    ///
+    /// Read a `.toml` or `.json` provider, or ask an executable to describe itself.
+    ///
+    /// An executable gets `{"version": 1, "request": "describe", "root": "<project>"}` on stdin
+    /// and must print the provider as JSON on stdout, then exit with 0.
+    ///
+    /// # Errors
+    ///
+    /// `SpawnFailed` when the executable can't start, `Plugin` when the provider is not valid or
+    /// the executable does not answer within `DESCRIBE`
+    ///
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |message: String| Error::Plugin {
+            path: path.to_path_buf(),
+            message,
+        };
+        let plugin: Self = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(read_to_string(path)?.as_str())
+                .map_err(|e| invalid(e.to_string()))?,
+            Some("json") => serde_json::from_str(read_to_string(path)?.as_str())
+                .map_err(|e| invalid(e.to_string()))?,
+            _ => serde_json::from_slice(describe(path, DESCRIBE)?.as_slice())
+                .map_err(|e| invalid(e.to_string()))?,
+        };
+        plugin.validate().map_err(invalid)?;
+        Ok(plugin)
+    }
+
+    #[doc = "Check the name and the commands of the provider"]
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err(String::from("the name is empty"));
+        }
+        if self.name.parse::<Language>().is_ok() {
+            return Err(format!("{} is a built-in language", self.name));
+        }
+        if let Some(task) = self.tasks.iter().find(|t| t.config.command.is_none()) {
+            return Err(format!("the task {} has no command", task.name));
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    #[doc = "Get the tasks of the provider"]
+    pub fn tasks(&self) -> Vec<Task> {
+        self.tasks
+            .iter()
+            .map(|plugin_task| {
+                let category: Category =
+                    Category::from_name(plugin_task.name.as_str()).unwrap_or(Category::Custom);
+                let mut task: Task = Task::new(plugin_task.name.as_str(), category, "");
+                plugin_task.config.clone().apply(&mut task);
+                task.provenance = Provenance::Plugin;
+                task
+            })
+            .collect()
+    }
+
+    #[must_use]
+    #[doc = "Check if a file marks a project of the language, by name or by extension"]
+    pub fn detected(&self, path: &Path) -> bool {
+        let by_name: bool = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| self.markers.iter().any(|m| m.eq(name)));
+        by_name || self.source(path)
+    }
+
+    #[must_use]
+    #[doc = "Check if a file belongs to the language"]
+    pub fn matches(&self, path: &Path) -> bool {
+        let by_name: bool = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| self.files.iter().chain(&self.markers).any(|f| f.eq(name)));
+        by_name || self.source(path)
+    }
+
+    #[doc = "Check if a file has a source extension of the language"]
+    fn source(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| self.extensions.iter().any(|e| e.eq(extension)))
+    }
+}
+
+#[doc = "Read a pipe of a plugin to its end in a thread, so a full pipe never blocks it"]
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    spawn(move || {
+        let mut data: Vec<u8> = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut data);
+        }
+        data
+    })
+}
+
+///
+/// # Describe
+///
+/// Run an executable plugin in its own process group and get its answer.
+///
+/// The group is killed when the plugin does not exit within the timeout.
+///
+/// # Errors
+///
+/// `SpawnFailed` when it can't start, `Plugin` when it times out or exits with an error
+///
+fn describe(path: &Path, timeout: Duration) -> Result<Vec<u8>> {
+    let request: Request = Request {
+        version: PROTOCOL,
+        request: "describe",
+        root: env::current_dir()?,
+    };
+    let mut cmd: Command = Command::new(path);
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| Error::SpawnFailed {
+            program: path.display().to_string(),
+        })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let line: String = serde_json::to_string(&request).unwrap_or_default();
+        let _ = writeln!(stdin, "{line}");
+    }
+    let stdout: JoinHandle<Vec<u8>> = drain(child.stdout.take());
+    let stderr: JoinHandle<Vec<u8>> = drain(child.stderr.take());
+    let started: Instant = Instant::now();
+    let status: ExitStatus = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            kill(child.id());
+            let _ = child.wait();
+            return Err(Error::Plugin {
+                path: path.to_path_buf(),
+                message: format!("no answer within {}s", timeout.as_secs_f64()),
+            });
+        }
+        sleep(POLL);
+    };
+    let stdout: Vec<u8> = stdout.join().unwrap_or_default();
+    let stderr: Vec<u8> = stderr.join().unwrap_or_default();
+    if !status.success() {
+        return Err(Error::Plugin {
+            path: path.to_path_buf(),
+            message: format!(
+                "exited with {status}: {}",
+                String::from_utf8_lossy(&stderr).trim()
+            ),
+        });
+    }
+    Ok(stdout)
+}
+
+#[cfg(unix)]
+#[doc = "Check if a plugin file can be executed"]
+fn executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+#[doc = "Check if a plugin file can be executed"]
+fn executable(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq("exe"))
+}
+
+#[must_use]
+#[doc = "Get the plugin directory, `$XDG_CONFIG_HOME/tux/plugins` or `~/.config/tux/plugins`"]
+pub fn directory() -> Option<PathBuf> {
+    config_home().map(|config| config.join(PLUGINS))
+}
+
+///
+/// # Plugins
+///
+/// Load the providers of the plugin directory, sorted by file name.
+///
+/// A provider which can't be loaded, or named like a previous one, is skipped with a warning on
+/// stderr, so a broken plugin never stops the checkup.
+///
+#[must_use]
+pub fn plugins() -> Vec<Plugin> {
+    let Some(dir) = directory() else {
+        return Vec::new();
+    };
+    let (plugins, errors) = providers(&dir);
+    for error in errors {
+        eprintln!("tux: plugin skipped, {error}");
+    }
+    plugins
+}
+
+///
+/// # Providers
+///
+/// Load the providers of a directory, sorted by file name, with the errors of the skipped ones.
+///
+/// The `.toml` and `.json` files are read, the other executable files are run.
+///
+#[must_use]
+pub fn providers(dir: &Path) -> (Vec<Plugin>, Vec<Error>) {
+    let Ok(entries) = read_dir(dir) else {
+        return (Vec::new(), Vec::new());
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            matches!(
+                path.extension().and_then(|e| e.to_str()),
+                Some("toml" | "json")
+            ) || executable(path)
+        })
+        .collect();
+    paths.sort();
+    let mut plugins: Vec<Plugin> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+    for path in paths {
+        match Plugin::load(&path) {
+            Ok(plugin) if plugins.iter().any(|p| p.name.eq(&plugin.name)) => {
+                errors.push(Error::Plugin {
+                    path,
+                    message: format!("{} is already provided", plugin.name),
+                });
+            }
+            Ok(plugin) => plugins.push(plugin),
+            Err(e) => errors.push(e),
+        }
+    }
+    (plugins, errors)
+}
+
+///
+/// # Detect
+///
+/// Walk the project tree, honoring the `.gitignore` rules, and get the names of the plugins
+/// having a marker or a source file
+///
+#[must_use]
+pub fn detect(root: &Path, plugins: &[Plugin]) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    for path in walk(root) {
+        if found.len() == plugins.len() {
+            break;
+        }
+        for plugin in plugins {
+            if !found.contains(&plugin.name) && plugin.detected(&path) {
+                found.push(plugin.name.clone());
+            }
+        }
+    }
+    plugins
+        .iter()
+        .filter(|plugin| found.contains(&plugin.name))
+        .map(|plugin| plugin.name.clone())
+        .collect()
+}
+
+#[must_use]
+#[doc = "Get the names of the plugins owning at least one of the changed paths"]
+pub fn languages(paths: &[PathBuf], plugins: &[Plugin]) -> Vec<String> {
+    plugins
+        .iter()
+        .filter(|plugin| paths.iter().any(|path| plugin.matches(path)))
+        .map(|plugin| plugin.name.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::CommandLine;
+    use std::{fs, process};
+
+    #[doc = "A temporary plugin directory"]
+    fn directory(name: &str) -> PathBuf {
+        let dir: PathBuf = PathBuf::from(format!("target/zuu-plugin-{name}-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[cfg(unix)]
+    #[doc = "Write an executable plugin running the shell script"]
+    fn executable(dir: &Path, name: &str, script: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path: PathBuf = dir.join(name);
+        fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn toml_and_json_providers_are_read() {
+        let dir: PathBuf = directory("read");
+        fs::write(
+            dir.join("jsonnet.toml"),
+            "name = \"Jsonnet\"\nextensions = [\"jsonnet\"]\nmarkers = [\"jsonnetfile.json\"]\n\
+             [[tasks]]\nname = \"format\"\ncommand = \"jsonnetfmt --test main.jsonnet\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("nix.json"),
+            r#"{"name": "Nix", "files": ["flake.lock"], "tasks": [{"name": "check", "command": "nix flake check"}]}"#,
+        )
+        .unwrap();
+        let (plugins, errors) = providers(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(plugins.len(), 2);
+        let tasks: Vec<Task> = plugins[0].tasks();
+        assert_eq!(plugins[0].name, "Jsonnet");
+        assert_eq!(tasks[0].category, Category::Format);
+        assert_eq!(tasks[0].command, "jsonnetfmt --test main.jsonnet");
+        assert_eq!(tasks[0].provenance, Provenance::Plugin);
+        assert!(plugins[0].detected(Path::new("lib/util.jsonnet")));
+        assert_eq!(plugins[1].name, "Nix");
+        assert_eq!(plugins[1].tasks()[0].category, Category::Custom);
+        assert!(plugins[1].matches(Path::new("flake.lock")));
+        assert!(!plugins[1].detected(Path::new("flake.lock")));
+    }
+
+    #[test]
+    fn invalid_providers_are_rejected() {
+        let provider = |name: &str, command: Option<&str>| Plugin {
+            name: name.to_string(),
+            extensions: Vec::new(),
+            files: Vec::new(),
+            markers: Vec::new(),
+            tasks: vec![PluginTask {
+                name: String::from("test"),
+                config: TaskConfig {
+                    command: command.map(|line| CommandLine::Line(line.to_string())),
+                    ..TaskConfig::default()
+                },
+            }],
+        };
+        assert_eq!(provider("Nix", Some("nix flake check")).validate(), Ok(()));
+        assert_eq!(
+            provider(" ", Some("true")).validate(),
+            Err(String::from("the name is empty"))
+        );
+        assert_eq!(
+            provider("rust", Some("true")).validate(),
+            Err(String::from("rust is a built-in language"))
+        );
+        assert_eq!(
+            provider("Nix", None).validate(),
+            Err(String::from("the task test has no command"))
+        );
+    }
+
+    #[test]
+    fn broken_and_duplicate_providers_are_skipped() {
+        let dir: PathBuf = directory("skip");
+        fs::write(dir.join("a.toml"), "name = \"Nix\"\n").unwrap();
+        fs::write(dir.join("b.json"), r#"{"name": "Nix"}"#).unwrap();
+        fs::write(dir.join("c.toml"), "name = [\n").unwrap();
+        fs::write(dir.join("d.toml"), "name = \"Dhall\"\n").unwrap();
+        let (plugins, errors) = providers(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        let names: Vec<&str> = plugins.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Nix", "Dhall"]);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0]
+            .to_string()
+            .ends_with("b.json: Nix is already provided"));
+        assert!(matches!(&errors[1], Error::Plugin { path, .. } if path.ends_with("c.toml")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn executable_provider_answers_the_describe_request() {
+        let dir: PathBuf = directory("describe");
+        executable(
+            &dir,
+            "zig",
+            r#"read request
+case "$request" in
+  *'"request":"describe"'*) echo '{"name": "Zig", "extensions": ["zig"]}' ;;
+  *) exit 1 ;;
+esac"#,
+        );
+        executable(&dir, "broken", "echo oops >&2; exit 3");
+        let (plugins, errors) = providers(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].name, "Zig");
+        assert!(plugins[0].matches(Path::new("build.zig")));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().ends_with("oops"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn silent_executable_provider_times_out() {
+        let dir: PathBuf = directory("timeout");
+        let path: PathBuf = executable(&dir, "slow", "sleep 5");
+        let started: Instant = Instant::now();
+        let answer: Result<Vec<u8>> = describe(&path, Duration::from_millis(200));
+        let elapsed: Duration = started.elapsed();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            matches!(answer, Err(Error::Plugin { message, .. }) if message.eq("no answer within 0.2s"))
+        );
+        assert!(elapsed < Duration::from_secs(2));
+    }
+}
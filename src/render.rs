@@ -0,0 +1,422 @@
+use crate::ask::TaskStatus;
+use crate::error::{Error, Result};
+use crate::output::{interrupted, ko, ok, plain, quiet, skip, terminal, SPINNERS};
+use crate::task::{Task, TaskResult};
+use crossterm::{
+    cursor::{Hide, MoveTo},
+    execute,
+    style::{Color, Print, SetForegroundColor},
+    terminal::{size, Clear, ClearType},
+};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::{
+    collections::BTreeMap,
+    io::stdout,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+#[doc = "The delay between two spinner frames"]
+pub const FRAME: Duration = Duration::from_millis(500);
+
+///
+/// # Renderer
+///
+/// Show the progress of the tasks.
+///
+/// The line is the position of the task in the run, offset by the runner line.
+/// A disabled task only gets `task_finished` with a skipped result.
+///
+pub trait Renderer: Send + Sync {
+    ///
+    /// # Errors
+    ///
+    /// On output failure
+    ///
+    fn task_started(&self, task: &Task, line: usize) -> Result<()>;
+
+    ///
+    /// # Errors
+    ///
+    /// On output failure
+    ///
+    fn task_progress(&self, task: &Task, line: usize, elapsed: Duration) -> Result<()>;
+
+    ///
+    /// # Errors
+    ///
+    /// On output failure
+    ///
+    fn task_finished(&self, task: &Task, result: &TaskResult, line: usize) -> Result<()>;
+
+    ///
+    /// # Errors
+    ///
+    /// On output failure
+    ///
+    fn run_finished(&self, results: &[TaskResult]) -> Result<()>;
+}
+
+#[must_use]
+#[doc = "Get a renderer by its command line name"]
+pub fn from_name(name: &str) -> Option<Arc<dyn Renderer>> {
+    match name {
+        "crossterm" => Some(Arc::new(Crossterm)),
+        "indicatif" => Some(Arc::new(Indicatif::new())),
+        "plain" => Some(Arc::new(Plain)),
+        "silent" => Some(Arc::new(Silent)),
+        _ => None,
+    }
+}
+
+#[must_use]
+#[doc = "Get the renderer matching the output mode, crossterm on a terminal"]
+pub fn auto() -> Arc<dyn Renderer> {
+    if quiet() {
+        return Arc::new(Silent);
+    }
+    if plain() {
+        return Arc::new(Plain);
+    }
+    Arc::new(Crossterm)
+}
+
+#[doc = "Get the message describing the outcome of a task"]
+fn outcome(task: &Task, result: &TaskResult) -> String {
+    if let Some(program) = &result.missing {
+        format!("{} ({program} not installed)", task.description)
+    } else if result.cached {
+        let message: &str = if result.status.eq(&TaskStatus::Pass) {
+            task.success.as_str()
+        } else {
+            task.failure.as_str()
+        };
+        format!("{message} (cached)")
+    } else if result.status.eq(&TaskStatus::Skipped) {
+        task.description.clone()
+    } else if result.timed_out {
+        format!("{} (timeout)", task.description)
+    } else if result.status.eq(&TaskStatus::Pass) {
+        task.success.clone()
+    } else if interrupted() {
+        format!("{} (interrupted)", task.description)
+    } else {
+        task.failure.clone()
+    }
+}
+
+#[doc = "The tux terminal output, drawing every task on its own line"]
+pub struct Crossterm;
+
+impl Renderer for Crossterm {
+    fn task_started(&self, task: &Task, line: usize) -> Result<()> {
+        let (Ok((cols, _row)), Ok(y)) = (size(), u16::try_from(line)) else {
+            return Err(Error::Terminal(std::io::Error::other(
+                "Failed to get the terminal size",
+            )));
+        };
+        let _drawing = terminal();
+        execute!(
+            stdout(),
+            MoveTo(0, y),
+            SetForegroundColor(Color::Green),
+            Print("*"),
+            MoveTo(2, y),
+            SetForegroundColor(Color::White),
+            Print(task.description.as_str()),
+            MoveTo(cols.saturating_sub(3), y),
+            SetForegroundColor(Color::White),
+            Print(" "),
+            SetForegroundColor(Color::Reset),
+        )
+        .map_err(Error::Terminal)
+    }
+
+    fn task_progress(&self, task: &Task, line: usize, elapsed: Duration) -> Result<()> {
+        let (Ok((cols, _row)), Ok(y)) = (size(), u16::try_from(line)) else {
+            return Ok(());
+        };
+        let frame: usize = usize::try_from(elapsed.as_millis() / FRAME.as_millis()).unwrap_or(0);
+        let spin: &str = SPINNERS[frame % SPINNERS.len()];
+        let _drawing = terminal();
+        execute!(
+            stdout(),
+            Hide,
+            SetForegroundColor(Color::Green),
+            MoveTo(0, y),
+            Print("*"),
+            MoveTo(2, y),
+            SetForegroundColor(Color::White),
+            Print(task.description.as_str()),
+            MoveTo(cols.saturating_sub(6), y),
+            SetForegroundColor(Color::Blue),
+            Print("["),
+            SetForegroundColor(Color::Green),
+            Print(format!(" {spin} ")),
+            SetForegroundColor(Color::Blue),
+            Print("]"),
+            SetForegroundColor(Color::Reset),
+        )
+        .map_err(Error::Terminal)
+    }
+
+    fn task_finished(&self, task: &Task, result: &TaskResult, line: usize) -> Result<()> {
+        if let Ok(y) = u16::try_from(line) {
+            let _drawing = terminal();
+            execute!(stdout(), MoveTo(0, y), Clear(ClearType::CurrentLine))
+                .map_err(Error::Terminal)?;
+        }
+        let message: String = outcome(task, result);
+        if result.status.eq(&TaskStatus::Skipped) {
+            skip(message.as_str(), line)
+        } else if result.status.eq(&TaskStatus::Pass) {
+            ok(message.as_str(), line)
+        } else {
+            ko(message.as_str(), line)
+        }
+    }
+
+    fn run_finished(&self, _results: &[TaskResult]) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[doc = "Plain lines on stderr, for logs and pipes"]
+pub struct Plain;
+
+impl Plain {
+    #[must_use]
+    #[doc = "Get the line of a started task"]
+    pub fn started(task: &Task) -> String {
+        format!("[ .. ] {}", task.description)
+    }
+
+    #[must_use]
+    #[doc = "Get the line of a finished task, with its status and outcome"]
+    pub fn finished(task: &Task, result: &TaskResult) -> String {
+        let status: &str = if result.status.eq(&TaskStatus::Skipped) {
+            "--"
+        } else if result.status.eq(&TaskStatus::Pass) {
+            "ok"
+        } else {
+            "!!"
+        };
+        format!("[ {status} ] {}", outcome(task, result))
+    }
+}
+
+impl Renderer for Plain {
+    fn task_started(&self, task: &Task, _line: usize) -> Result<()> {
+        eprintln!("{}", Self::started(task));
+        Ok(())
+    }
+
+    fn task_progress(&self, _task: &Task, _line: usize, _elapsed: Duration) -> Result<()> {
+        Ok(())
+    }
+
+    fn task_finished(&self, task: &Task, result: &TaskResult, _line: usize) -> Result<()> {
+        eprintln!("{}", Self::finished(task, result));
+        Ok(())
+    }
+
+    fn run_finished(&self, _results: &[TaskResult]) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[doc = "No output at all"]
+pub struct Silent;
+
+impl Renderer for Silent {
+    fn task_started(&self, _task: &Task, _line: usize) -> Result<()> {
+        Ok(())
+    }
+
+    fn task_progress(&self, _task: &Task, _line: usize, _elapsed: Duration) -> Result<()> {
+        Ok(())
+    }
+
+    fn task_finished(&self, _task: &Task, _result: &TaskResult, _line: usize) -> Result<()> {
+        Ok(())
+    }
+
+    fn run_finished(&self, _results: &[TaskResult]) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[doc = "A spinner per running task, drawn by indicatif"]
+pub struct Indicatif {
+    multi: MultiProgress,
+    bars: Mutex<BTreeMap<usize, ProgressBar>>,
+}
+
+impl Default for Indicatif {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicatif {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            multi: MultiProgress::new(),
+            bars: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    #[doc = "Create the bar of a task"]
+    fn bar(&self, task: &Task) -> ProgressBar {
+        let bar: ProgressBar = self.multi.add(ProgressBar::new_spinner());
+        if let Ok(style) = ProgressStyle::with_template("{spinner:.green} {msg} {elapsed:.dim}") {
+            bar.set_style(style);
+        }
+        bar.set_message(task.description.clone());
+        bar
+    }
+}
+
+impl Renderer for Indicatif {
+    fn task_started(&self, task: &Task, line: usize) -> Result<()> {
+        let bar: ProgressBar = self.bar(task);
+        bar.tick();
+        self.bars
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(line, bar);
+        Ok(())
+    }
+
+    fn task_progress(&self, _task: &Task, line: usize, _elapsed: Duration) -> Result<()> {
+        if let Some(bar) = self
+            .bars
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&line)
+        {
+            bar.tick();
+        }
+        Ok(())
+    }
+
+    fn task_finished(&self, task: &Task, result: &TaskResult, line: usize) -> Result<()> {
+        let bar: ProgressBar = self
+            .bars
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&line)
+            .unwrap_or_else(|| self.bar(task));
+        let (color, status): (&str, &str) = if result.status.eq(&TaskStatus::Skipped) {
+            ("yellow", "--")
+        } else if result.status.eq(&TaskStatus::Pass) {
+            ("green", "ok")
+        } else {
+            ("red", "!!")
+        };
+        if let Ok(style) =
+            ProgressStyle::with_template(format!("[{{prefix:.{color}}}] {{msg}}").as_str())
+        {
+            bar.set_style(style);
+        }
+        bar.set_prefix(format!(" {status} "));
+        bar.finish_with_message(outcome(task, result));
+        Ok(())
+    }
+
+    fn run_finished(&self, _results: &[TaskResult]) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::{Category, Exit};
+    use std::{path::Path, thread::scope};
+
+    #[doc = "The logs of the tasks, never written"]
+    const LOGS: &str = "target/zuu-render-none";
+
+    #[doc = "The result of a task exiting with the code"]
+    fn exited(task: &Task, code: i32) -> TaskResult {
+        let exit: Exit = Exit {
+            code: Some(code),
+            ..Exit::default()
+        };
+        TaskResult::new(task, &exit, Path::new(LOGS))
+    }
+
+    #[test]
+    fn plain_writes_a_status_line_per_task() {
+        let task: Task = Task::new("test", Category::Test, "cargo test");
+        let timed_out: TaskResult = TaskResult {
+            timed_out: true,
+            status: TaskStatus::Timeout,
+            ..exited(&task, 1)
+        };
+        let cached: TaskResult = TaskResult {
+            cached: true,
+            ..exited(&task, 0)
+        };
+        let missing: TaskResult = TaskResult::not_installed(&task, "cargo", Path::new(LOGS));
+        let skipped: TaskResult = TaskResult::skipped(&task, Path::new(LOGS));
+        assert_eq!(Plain::started(&task), "[ .. ] test");
+        assert_eq!(
+            Plain::finished(&task, &exited(&task, 0)),
+            "[ ok ] test passed"
+        );
+        assert_eq!(
+            Plain::finished(&task, &exited(&task, 1)),
+            "[ !! ] test failed"
+        );
+        assert_eq!(Plain::finished(&task, &timed_out), "[ !! ] test (timeout)");
+        assert_eq!(
+            Plain::finished(&task, &cached),
+            "[ ok ] test passed (cached)"
+        );
+        assert_eq!(
+            Plain::finished(&task, &missing),
+            "[ !! ] test (cargo not installed)"
+        );
+        assert_eq!(Plain::finished(&task, &skipped), "[ -- ] test");
+    }
+
+    #[test]
+    fn indicatif_keys_the_bars_by_line_across_threads() {
+        let renderer: Indicatif = Indicatif::new();
+        let tasks: Vec<Task> = (0..8)
+            .map(|index| Task::new(format!("task-{index}").as_str(), Category::Custom, "true"))
+            .collect();
+        scope(|threads| {
+            for (line, task) in tasks.iter().enumerate() {
+                let renderer: &Indicatif = &renderer;
+                threads.spawn(move || {
+                    renderer.task_started(task, line).unwrap();
+                    renderer
+                        .task_progress(task, line, Duration::from_millis(10))
+                        .unwrap();
+                });
+            }
+        });
+        let started: Vec<usize> = renderer.bars.lock().unwrap().keys().copied().collect();
+        scope(|threads| {
+            for (line, task) in tasks.iter().enumerate().skip(1) {
+                let renderer: &Indicatif = &renderer;
+                threads.spawn(move || {
+                    renderer
+                        .task_finished(task, &exited(task, 0), line)
+                        .unwrap();
+                });
+            }
+        });
+        renderer
+            .task_finished(&tasks[0], &exited(&tasks[0], 0), 42)
+            .unwrap();
+        let running: Vec<usize> = renderer.bars.lock().unwrap().keys().copied().collect();
+        renderer.run_finished(&[]).unwrap();
+        assert_eq!(started, (0..8).collect::<Vec<usize>>());
+        assert_eq!(running, [0]);
+    }
+}
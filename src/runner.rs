@@ -1,4 +1,18 @@
-use std::{fs::create_dir_all, io::Error};
+use crate::ask::TaskStatus;
+use crate::cache::Cache;
+use crate::error::{Error, Result};
+use crate::output::{interrupted, run};
+use crate::render::{auto, Renderer};
+use crate::task::{Exit, Selection, Task, TaskResult};
+use crate::tool::{locate, missing, not_found, NOT_FOUND};
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, File},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Arc,
+    time::Duration,
+};
 
 ///
 /// # Create
@@ -7,12 +21,339 @@ use std::{fs::create_dir_all, io::Error};
 ///
 /// # Errors
 ///
-/// On no write rights
+/// `Io` on no write rights
 ///
-pub fn create_zuu() -> Result<(), Error> {
-    if create_dir_all("zuu").is_ok() {
-        Ok(())
-    } else {
-        Err(Error::other("Failed to create zuu structure"))
+pub fn create_zuu() -> Result<()> {
+    Ok(create_dir_all("zuu")?)
+}
+
+#[derive(Clone)]
+#[doc = "Run tasks one after another and collect their results"]
+pub struct TaskRunner {
+    tasks: Vec<Task>,
+    directory: Option<PathBuf>,
+    env: BTreeMap<String, String>,
+    logs: PathBuf,
+    renderer: Arc<dyn Renderer>,
+    cache: Option<Cache>,
+    selection: Selection,
+    strict: bool,
+    fast_first: bool,
+    line: usize,
+}
+
+impl Default for TaskRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskRunner {
+    #[must_use]
+    #[doc = "A runner without tasks, writing its logs in zuu with the renderer of the output mode"]
+    pub fn new() -> Self {
+        Self {
+            tasks: Vec::new(),
+            directory: None,
+            env: BTreeMap::new(),
+            logs: PathBuf::from("zuu"),
+            renderer: auto(),
+            cache: None,
+            selection: Selection::default(),
+            strict: false,
+            fast_first: false,
+            line: 0,
+        }
+    }
+
+    #[must_use]
+    #[doc = "Add a task to run"]
+    pub fn task(mut self, task: Task) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Add tasks to run"]
+    pub fn tasks(mut self, tasks: impl IntoIterator<Item = Task>) -> Self {
+        self.tasks.extend(tasks);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set the working directory of the tasks without their own"]
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set an environment variable for every task, the task env takes precedence"]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set the directory receiving the stdout and stderr logs"]
+    pub fn logs(mut self, logs: impl Into<PathBuf>) -> Self {
+        self.logs = logs.into();
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set the renderer showing the progress, shared with other runners"]
+    pub fn renderer(mut self, renderer: Arc<dyn Renderer>) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Reuse the results of the tasks whose inputs are unchanged"]
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Skip the tasks not selected"]
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Stop on the first failure"]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Stop before the slow tasks once a fast one failed"]
+    pub const fn fast_first(mut self, fast_first: bool) -> Self {
+        self.fast_first = fast_first;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Set the terminal line of the first task"]
+    pub const fn line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    ///
+    /// # Run
+    ///
+    /// Run the tasks of the runner
+    ///
+    /// # Errors
+    ///
+    /// On log, spawn or terminal failure, a failed task is only a failed result
+    ///
+    pub fn run(&self) -> Result<Vec<TaskResult>> {
+        self.run_tasks(&self.tasks)
+    }
+
+    ///
+    /// # Run tasks
+    ///
+    /// Run tasks with the settings of the runner.
+    ///
+    /// The results stop at the first failure in strict mode, or on Ctrl-C.
+    ///
+    /// # Errors
+    ///
+    /// On log, spawn or terminal failure, a failed task is only a failed result
+    ///
+    pub fn run_tasks(&self, tasks: &[Task]) -> Result<Vec<TaskResult>> {
+        create_dir_all(self.logs.join("stdout"))?;
+        create_dir_all(self.logs.join("stderr"))?;
+        let mut results: Vec<TaskResult> = Vec::new();
+        for (index, task) in tasks.iter().enumerate() {
+            let line: usize = self.line + index;
+            if self.fast_first && !task.category.fast() && results.iter().any(failed) {
+                break;
+            }
+            if !self.selection.enabled(task) {
+                let result: TaskResult = TaskResult::skipped(task, &self.logs);
+                self.renderer.task_finished(task, &result, line)?;
+                results.push(result);
+                continue;
+            }
+            let key: Option<String> = self
+                .cache
+                .as_ref()
+                .filter(|_| Cache::cacheable(task))
+                .map(|cache| cache.key(task, &self.logs));
+            let cached: Option<TaskResult> = self
+                .cache
+                .as_ref()
+                .zip(key.as_deref())
+                .and_then(|(cache, key)| cache.load(key));
+            let directory: &Path = task
+                .directory
+                .as_deref()
+                .or(self.directory.as_deref())
+                .unwrap_or_else(|| Path::new("."));
+            let result: TaskResult = if let Some(program) = missing(task, directory) {
+                TaskResult::not_installed(task, &program, &self.logs)
+            } else if let Some(result) = cached {
+                result
+            } else {
+                self.renderer.task_started(task, line)?;
+                let mut result: TaskResult = match self.execute(task, line) {
+                    Ok(exit) => TaskResult::new(task, &exit, &self.logs),
+                    Err(e @ (Error::SpawnFailed { .. } | Error::Command { .. })) => {
+                        TaskResult::error(task, e.to_string().as_str(), &self.logs)
+                    }
+                    Err(e) => return Err(e),
+                };
+                if result.code.eq(&Some(NOT_FOUND)) {
+                    result.missing = not_found(&result.tail);
+                }
+                if result.missing.is_some() {
+                    result.status = TaskStatus::Error;
+                }
+                if let (Some(cache), Some(key), false) = (&self.cache, &key, interrupted()) {
+                    cache.store(key, &result)?;
+                }
+                result
+            };
+            self.renderer.task_finished(task, &result, line)?;
+            results.push(result);
+            if interrupted() || (self.strict && results.iter().any(failed)) {
+                break;
+            }
+        }
+        self.renderer.run_finished(&results)?;
+        Ok(results)
+    }
+
+    ///
+    /// # Execute
+    ///
+    /// Run a task, writing its output in the logs.
+    ///
+    /// The command is split in arguments and started directly, or given to `sh -c` for a shell task.
+    ///
+    /// # Errors
+    ///
+    /// `Command` when the command can't run without a shell, `SpawnFailed` or `Io`
+    ///
+    fn execute(&self, task: &Task, line: usize) -> Result<Exit> {
+        let output_file: String = task.output_file();
+        let mut cmd: Command = if task.shell {
+            let mut sh: Command = Command::new("sh");
+            sh.arg("-c").arg(task.command.as_str());
+            sh
+        } else {
+            let argv: Vec<String> = task.argv()?;
+            let program: PathBuf = match task.directory.as_ref().or(self.directory.as_ref()) {
+                Some(directory) => locate(&argv[0], directory),
+                None => PathBuf::from(&argv[0]),
+            };
+            let mut direct: Command = Command::new(program);
+            direct.args(&argv[1..]);
+            direct
+        };
+        cmd.stdout(File::create(self.logs.join("stdout").join(&output_file))?)
+            .stderr(File::create(self.logs.join("stderr").join(&output_file))?)
+            .envs(&self.env);
+        if let Some(directory) = &self.directory {
+            cmd.current_dir(directory);
+        }
+        task.prepare(&mut cmd);
+        let timeout: Option<Duration> = task.timeout.map(Duration::from_secs);
+        run(&mut cmd, timeout, &mut |elapsed| {
+            self.renderer.task_progress(task, line, elapsed)
+        })
+    }
+}
+
+#[doc = "Check if an executed task failed"]
+fn failed(result: &TaskResult) -> bool {
+    result.status.failed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::join;
+    use crate::render::Silent;
+    use crate::task::Category;
+    use std::{fs, process};
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_program_runs_in_the_task_directory() {
+        use std::os::unix::fs::PermissionsExt;
+        let root: PathBuf = PathBuf::from(format!("target/zuu-runner-{}", process::id()));
+        let api: PathBuf = root.join("api");
+        fs::create_dir_all(&api).unwrap();
+        let script: PathBuf = api.join("check.sh");
+        fs::write(&script, "#!/bin/sh\ntest -f check.sh\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let mut task: Task = Task::new("check", Category::Test, "./check.sh");
+        task.directory = Some(api);
+        let results: Vec<TaskResult> = TaskRunner::new()
+            .logs(root.join("zuu"))
+            .renderer(Arc::new(Silent))
+            .run_tasks(&[task])
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(results[0].status, TaskStatus::Pass);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn quoted_program_is_installed() {
+        use std::os::unix::fs::PermissionsExt;
+        let root: PathBuf = PathBuf::from(format!("target/zuu-quoted-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let script: PathBuf = root.join("my check.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let command: String = join(&[String::from("./my check.sh")]);
+        let mut task: Task = Task::new("check", Category::Test, command.as_str());
+        task.directory = Some(root.clone());
+        let results: Vec<TaskResult> = TaskRunner::new()
+            .logs(root.join("zuu"))
+            .renderer(Arc::new(Silent))
+            .run_tasks(&[task])
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(results[0].missing, None);
+        assert_eq!(results[0].status, TaskStatus::Pass);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn timeout_kills_the_task_and_its_children() {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-timeout-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let mut task: Task = Task::new(
+            "slow",
+            Category::Test,
+            "sleep 5 & echo $! > sleep.pid; wait",
+        );
+        task.shell = true;
+        task.timeout = Some(1);
+        task.directory = Some(root.clone());
+        let results: Vec<TaskResult> = TaskRunner::new()
+            .logs(root.join("zuu"))
+            .renderer(Arc::new(Silent))
+            .run_tasks(&[task])
+            .unwrap();
+        let pid: String = fs::read_to_string(root.join("sleep.pid")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        let stat: PathBuf = PathBuf::from(format!("/proc/{}/stat", pid.trim()));
+        let alive: bool = fs::read_to_string(stat)
+            .is_ok_and(|stat| !stat.split(") ").nth(1).is_some_and(|s| s.starts_with('Z')));
+        assert_eq!(results[0].status, TaskStatus::Timeout);
+        assert!(results[0].timed_out);
+        assert!(results[0].duration < 3.0);
+        assert!(!alive);
     }
 }
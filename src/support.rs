@@ -1,6 +1,24 @@
+use crate::error::Error;
+use crate::node::{manage, typecheck};
+use crate::task::{BuiltinTask, Task};
+use crate::tool::program;
+use crate::{
+    BASH_TASK, CPP_TASK, CRYSTAL_TASK, C_TASK, DART_TASK, D_TASK, ELIXIR_TASK, FISH_TASK,
+    FSHARP_TASK, GO_TASK, HASKELL_TASK, JAVA_TASK, KOTLIN_TASK, LUA_TASK, NIM_TASK, NODEJS_TASK,
+    OBJC_TASK, PERL_TASK, PHP_TASK, PYTHON_TASK, RUBY_TASK, RUST_TASK, R_TASK, SCALA_TASK,
+    SWIFT_TASK, TYPESCRIPT_TASK, ZSH_TASK,
+};
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-#[derive(PartialEq)]
+#[doc = "The directories never scanned by the detection"]
+pub const IGNORED: [&str; 3] = ["zuu", "node_modules", "target"];
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum Language {
     Rust,
     JavaScript,
@@ -34,120 +52,763 @@ pub enum Language {
 
 impl Display for Language {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+
+    ///
+    /// # From str
+    ///
+    /// Find a supported language by its name or one of its aliases, ignoring the case
+    ///
+    /// # Errors
+    ///
+    /// `UnknownLanguage` when no supported language has this name
+    ///
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let wanted: String = name.trim().to_lowercase();
+        Self::all()
+            .into_iter()
+            .find(|language| {
+                language.name().to_lowercase().eq(&wanted)
+                    || language.aliases().contains(&wanted.as_str())
+            })
+            .ok_or_else(|| Error::UnknownLanguage {
+                name: name.to_string(),
+            })
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = Error;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        language.name().to_string()
+    }
+}
+
+impl Language {
+    #[must_use]
+    #[doc = "Get all supported languages, in the order proposed by tux init"]
+    pub const fn all() -> [Self; 27] {
+        [
+            Self::Rust,
+            Self::JavaScript,
+            Self::TypeScript,
+            Self::Go,
+            Self::D,
+            Self::Python,
+            Self::Php,
+            Self::Java,
+            Self::Kotlin,
+            Self::Swift,
+            Self::Scala,
+            Self::Ruby,
+            Self::Perl,
+            Self::R,
+            Self::Haskell,
+            Self::Lua,
+            Self::ObjectiveC,
+            Self::C,
+            Self::Cpp,
+            Self::Nim,
+            Self::Crystal,
+            Self::FSharp,
+            Self::Dart,
+            Self::Elixir,
+            Self::Bash,
+            Self::Zsh,
+            Self::Fish,
+        ]
+    }
+
+    #[must_use]
+    #[doc = "Get the canonical name of the language, used in tux.toml and in the reports"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::JavaScript => "JavaScript",
+            Self::TypeScript => "TypeScript",
+            Self::Go => "Go",
+            Self::D => "D",
+            Self::Python => "Python",
+            Self::Php => "Php",
+            Self::Java => "Java",
+            Self::Kotlin => "Kotlin",
+            Self::Swift => "Swift",
+            Self::Scala => "Scala",
+            Self::Ruby => "Ruby",
+            Self::Perl => "Perl",
+            Self::R => "R",
+            Self::Haskell => "Haskell",
+            Self::Lua => "Lua",
+            Self::ObjectiveC => "ObjectiveC",
+            Self::C => "C",
+            Self::Cpp => "Cpp",
+            Self::Nim => "Nim",
+            Self::Crystal => "Crystal",
+            Self::FSharp => "FSharp",
+            Self::Dart => "Dart",
+            Self::Elixir => "Elixir",
+            Self::Bash => "Bash",
+            Self::Zsh => "Zsh",
+            Self::Fish => "Fish",
+            Self::Unknown => "Unknown",
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the other names accepted in tux.toml, in lowercase"]
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["rs"],
+            Self::JavaScript => &["js", "node", "nodejs"],
+            Self::TypeScript => &["ts"],
+            Self::Go => &["golang"],
+            Self::D => &["dlang"],
+            Self::Python => &["py", "python3"],
+            Self::Php => &[],
+            Self::Kotlin => &["kt"],
+            Self::Ruby => &["rb"],
+            Self::Perl => &["pl"],
+            Self::Haskell => &["hs"],
+            Self::ObjectiveC => &["objc", "objective-c"],
+            Self::Cpp => &["c++", "cxx"],
+            Self::Crystal => &["cr"],
+            Self::FSharp => &["f#", "fs"],
+            Self::Elixir => &["ex"],
+            Self::Bash => &["sh"],
+            Self::Java | Self::Swift | Self::Scala | Self::R | Self::Lua => &[],
+            Self::C | Self::Nim | Self::Dart | Self::Zsh | Self::Fish => &[],
+            Self::Unknown => &[],
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the source file extensions of the language"]
+    pub const fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["rs"],
+            Self::JavaScript => &["js", "mjs", "cjs", "jsx"],
+            Self::TypeScript => &["ts", "mts", "cts", "tsx"],
+            Self::Go => &["go"],
+            Self::D => &["d"],
+            Self::Python => &["py", "pyi"],
+            Self::Php => &["php"],
+            Self::Java => &["java"],
+            Self::Kotlin => &["kt", "kts"],
+            Self::Swift => &["swift"],
+            Self::Scala => &["scala", "sc"],
+            Self::Ruby => &["rb"],
+            Self::Perl => &["pl", "pm", "t"],
+            Self::R => &["r", "R", "Rmd"],
+            Self::Haskell => &["hs", "lhs", "cabal"],
+            Self::Lua => &["lua", "rockspec"],
+            Self::ObjectiveC => &["m", "mm", "h"],
+            Self::C => &["c", "h"],
+            Self::Cpp => &["cpp", "cc", "cxx", "hpp", "hh", "hxx", "h"],
+            Self::Nim => &["nim", "nims", "nimble"],
+            Self::Crystal => &["cr"],
+            Self::FSharp => &["fs", "fsi", "fsx", "fsproj"],
+            Self::Dart => &["dart"],
+            Self::Elixir => &["ex", "exs"],
+            Self::Bash => &["sh", "bash"],
+            Self::Zsh => &["zsh"],
+            Self::Fish => &["fish"],
+            Self::Unknown => &[],
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the project files of the language, like manifests and lockfiles"]
+    pub const fn files(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["Cargo.toml", "Cargo.lock"],
+            Self::JavaScript => &[
+                "package.json",
+                "package-lock.json",
+                "npm-shrinkwrap.json",
+                "yarn.lock",
+                ".yarnrc.yml",
+                "pnpm-lock.yaml",
+                "bun.lock",
+                "bun.lockb",
+            ],
+            Self::TypeScript => &[
+                "tsconfig.json",
+                "package.json",
+                "package-lock.json",
+                "yarn.lock",
+                "pnpm-lock.yaml",
+                "bun.lock",
+            ],
+            Self::Go => &["go.mod", "go.sum"],
+            Self::D => &["dub.json", "dub.sdl"],
+            Self::Python => &[
+                "pyproject.toml",
+                "setup.py",
+                "setup.cfg",
+                "requirements.txt",
+            ],
+            Self::Php => &["composer.json", "composer.lock"],
+            Self::Java => &["pom.xml", "build.gradle"],
+            Self::Kotlin => &["build.gradle.kts"],
+            Self::Swift => &["Package.swift"],
+            Self::Scala => &["build.sbt"],
+            Self::Ruby => &["Gemfile", "Gemfile.lock"],
+            Self::Perl => &["Makefile.PL", "cpanfile"],
+            Self::R => &["DESCRIPTION"],
+            Self::Haskell => &["stack.yaml", "cabal.project"],
+            Self::Lua => &[".luacheckrc"],
+            Self::ObjectiveC => &["Podfile"],
+            Self::C | Self::Cpp => &["Makefile", "CMakeLists.txt"],
+            Self::Nim => &["nim.cfg"],
+            Self::Crystal => &["shard.yml"],
+            Self::FSharp => &["paket.dependencies"],
+            Self::Dart => &["pubspec.yaml", "pubspec.lock"],
+            Self::Elixir => &["mix.exs", "mix.lock"],
+            Self::Bash | Self::Zsh | Self::Fish | Self::Unknown => &[],
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the files marking a project of the language"]
+    pub const fn markers(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["Cargo.toml"],
+            Self::JavaScript => &["package.json"],
+            Self::TypeScript => &["tsconfig.json"],
+            Self::Go => &["go.mod"],
+            Self::D => &["dub.json", "dub.sdl"],
+            Self::Python => &["pyproject.toml", "setup.py", "requirements.txt"],
+            Self::Php => &["composer.json"],
+            Self::Java => &["pom.xml", "build.gradle"],
+            Self::Kotlin => &["build.gradle.kts"],
+            Self::Swift => &["Package.swift"],
+            Self::Scala => &["build.sbt"],
+            Self::Ruby => &["Gemfile"],
+            Self::Perl => &["Makefile.PL", "cpanfile"],
+            Self::R => &["DESCRIPTION"],
+            Self::Haskell => &["stack.yaml", "cabal.project"],
+            Self::ObjectiveC => &["Podfile"],
+            Self::Crystal => &["shard.yml"],
+            Self::Dart => &["pubspec.yaml"],
+            Self::Elixir => &["mix.exs"],
+            Self::Lua | Self::C | Self::Cpp | Self::Nim | Self::FSharp => &[],
+            Self::Bash | Self::Zsh | Self::Fish | Self::Unknown => &[],
+        }
+    }
+
+    ///
+    /// # Configs
+    ///
+    /// Get the configuration files of the tools of the language, read by its tasks.
+    ///
+    /// A name ending with `*` matches any file starting with it, like `.eslintrc.json`.
+    ///
+    #[must_use]
+    pub const fn configs(self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &[
+                "deny.toml",
+                "clippy.toml",
+                ".clippy.toml",
+                "rustfmt.toml",
+                ".rustfmt.toml",
+                "rust-toolchain",
+                "rust-toolchain.toml",
+                "config.toml",
+            ],
+            Self::JavaScript | Self::TypeScript => &[
+                ".eslintrc*",
+                "eslint.config.*",
+                ".eslintignore",
+                ".prettierrc*",
+                "prettier.config.*",
+                ".prettierignore",
+                "tsconfig*.json",
+                "typedoc.json",
+                "jest.config.*",
+                "vitest.config.*",
+                ".npmrc",
+                ".nvmrc",
+                ".node-version",
+            ],
+            Self::Go => &[".golangci.yml", ".golangci.yaml", ".golangci.toml"],
+            Self::Python => &[
+                "setup.cfg",
+                "tox.ini",
+                ".flake8",
+                "mypy.ini",
+                ".mypy.ini",
+                "pytest.ini",
+                ".bandit",
+                ".python-version",
+            ],
+            Self::Php => &["phpunit.xml*", "phpstan.neon*", ".php-cs-fixer*"],
+            Self::Ruby => &[".rubocop.yml", ".yardopts", ".ruby-version"],
+            Self::Swift => &[".swiftlint.yml", ".swiftformat"],
+            Self::Lua => &[".luacheckrc", ".busted", "config.ld"],
+            Self::Perl => &[".perlcriticrc", ".perltidyrc"],
+            Self::Haskell => &[".hlint.yaml", "stack.yaml"],
+            Self::C | Self::Cpp | Self::ObjectiveC => &[".clang-format", ".clang-tidy"],
+            Self::Bash | Self::Zsh | Self::Fish => &[".shellcheckrc"],
+            Self::Dart => &["analysis_options.yaml"],
+            Self::Elixir => &[".formatter.exs", ".credo.exs"],
+            Self::D
+            | Self::Java
+            | Self::Kotlin
+            | Self::Scala
+            | Self::R
+            | Self::Nim
+            | Self::Crystal
+            | Self::FSharp
+            | Self::Unknown => &[],
+        }
+    }
+
+    #[must_use]
+    #[doc = "Check if a file configures a tool of the language"]
+    pub fn configured(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+        self.configs()
+            .iter()
+            .any(|config| match config.split_once('*') {
+                Some((prefix, suffix)) => {
+                    name.starts_with(prefix) && name[prefix.len()..].ends_with(suffix)
+                }
+                None => name.eq(*config),
+            })
+    }
+
+    #[must_use]
+    #[doc = "Get the build systems and package managers commonly driving a project of the language"]
+    pub const fn build_systems(self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => &["cargo"],
+            Self::JavaScript => &["npm", "yarn", "pnpm", "bun"],
+            Self::TypeScript => &["tsc", "npm", "yarn", "pnpm", "bun"],
+            Self::Go => &["go"],
+            Self::D => &["dub"],
+            Self::Python => &["pip", "poetry", "hatch", "setuptools"],
+            Self::Php => &["composer"],
+            Self::Java => &["maven", "gradle"],
+            Self::Kotlin => &["gradle"],
+            Self::Swift => &["swift package", "xcodebuild"],
+            Self::Scala => &["sbt"],
+            Self::Ruby => &["bundler", "rake"],
+            Self::Perl => &["cpan", "make"],
+            Self::R => &["R CMD"],
+            Self::Haskell => &["stack", "cabal"],
+            Self::Lua => &["luarocks"],
+            Self::ObjectiveC => &["xcodebuild", "cocoapods"],
+            Self::C | Self::Cpp => &["make", "cmake"],
+            Self::Nim => &["nimble"],
+            Self::Crystal => &["shards"],
+            Self::FSharp => &["dotnet", "paket"],
+            Self::Dart => &["dart pub"],
+            Self::Elixir => &["mix"],
+            Self::Bash | Self::Zsh | Self::Fish | Self::Unknown => &[],
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the built-in tasks of the language"]
+    pub const fn tasks(self) -> &'static [BuiltinTask] {
+        match self {
+            Self::Rust => RUST_TASK,
+            Self::JavaScript => NODEJS_TASK,
+            Self::TypeScript => TYPESCRIPT_TASK,
+            Self::Go => GO_TASK,
+            Self::D => D_TASK,
+            Self::Python => PYTHON_TASK,
+            Self::Php => PHP_TASK,
+            Self::Java => JAVA_TASK,
+            Self::Kotlin => KOTLIN_TASK,
+            Self::Swift => SWIFT_TASK,
+            Self::Scala => SCALA_TASK,
+            Self::Ruby => RUBY_TASK,
+            Self::Perl => PERL_TASK,
+            Self::R => R_TASK,
+            Self::Haskell => HASKELL_TASK,
+            Self::Lua => LUA_TASK,
+            Self::ObjectiveC => OBJC_TASK,
+            Self::C => C_TASK,
+            Self::Cpp => CPP_TASK,
+            Self::Nim => NIM_TASK,
+            Self::Crystal => CRYSTAL_TASK,
+            Self::FSharp => FSHARP_TASK,
+            Self::Dart => DART_TASK,
+            Self::Elixir => ELIXIR_TASK,
+            Self::Bash => BASH_TASK,
+            Self::Zsh => ZSH_TASK,
+            Self::Fish => FISH_TASK,
+            Self::Unknown => &[],
+        }
+    }
+
+    ///
+    /// # Project tasks
+    ///
+    /// Get the built-in tasks of the language for a project directory.
+    ///
+    /// The JavaScript and TypeScript tasks use the package manager of the project, and the
+    /// TypeScript type-checking runs once per root tsconfig of the project.
+    ///
+    #[must_use]
+    pub fn project_tasks(self, directory: &Path) -> Vec<Task> {
+        let tasks: Vec<Task> = self.tasks().iter().map(Task::from).collect();
         match self {
-            Language::Rust => write!(f, "Rust"),
-            Language::JavaScript => write!(f, "JavaScript"),
-            Language::TypeScript => write!(f, "TypeScript"),
-            Language::Go => write!(f, "Go"),
-            Language::D => write!(f, "D"),
-            Language::Python => write!(f, "Python"),
-            Language::Php => write!(f, "Php"),
-            Language::Java => write!(f, "Java"),
-            Language::Kotlin => write!(f, "Kotlin"),
-            Language::Swift => write!(f, "Swift"),
-            Language::Scala => write!(f, "Scala"),
-            Language::Ruby => write!(f, "Ruby"),
-            Language::Perl => write!(f, "Perl"),
-            Language::R => write!(f, "R"),
-            Language::Haskell => write!(f, "Haskell"),
-            Language::Lua => write!(f, "Variant1"),
-            Language::ObjectiveC => write!(f, "ObjectiveC"),
-            Language::C => write!(f, "C"),
-            Language::Cpp => write!(f, "Cpp"),
-            Language::Nim => write!(f, "Nim"),
-            Language::Crystal => write!(f, "Crystal"),
-            Language::FSharp => write!(f, "FSharp"),
-            Language::Dart => write!(f, "Dart"),
-            Language::Elixir => write!(f, "Elixir"),
-            Language::Bash => write!(f, "Bash"),
-            Language::Zsh => write!(f, "Zsh"),
-            Language::Fish => write!(f, "Fish"),
-            Language::Unknown => write!(f, "Unknown"),
+            Self::JavaScript => manage(directory, tasks),
+            Self::TypeScript => manage(directory, typecheck(directory, tasks)),
+            _ => tasks,
         }
     }
+
+    ///
+    /// # Tools
+    ///
+    /// Get the external programs called by the built-in tasks, in task order and without duplicates.
+    ///
+    /// A cargo subcommand not shipped with cargo is its `cargo-<name>` program.
+    ///
+    #[must_use]
+    pub fn tools(self) -> Vec<String> {
+        let mut tools: Vec<String> = Vec::new();
+        for task in self.tasks() {
+            if let Some(name) = program(task.command) {
+                if !tools.contains(&name) {
+                    tools.push(name);
+                }
+            }
+        }
+        tools
+    }
+
+    #[must_use]
+    #[doc = "Get all the metadata of the language"]
+    pub fn metadata(self) -> Metadata {
+        Metadata {
+            language: self,
+            extensions: self.extensions(),
+            files: self.files(),
+            markers: self.markers(),
+            configs: self.configs(),
+            build_systems: self.build_systems(),
+            tools: self.tools(),
+        }
+    }
+
+    ///
+    /// # From path
+    ///
+    /// Find the language of a file by its name, like `Cargo.toml`, or by its extension.
+    ///
+    /// A project file shared by several languages, like `package.json`, belongs to the first one,
+    /// and a `.h` header is C.
+    ///
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        if path.extension().is_some_and(|extension| extension.eq("h")) {
+            return Some(Self::C);
+        }
+        Self::all()
+            .into_iter()
+            .find(|language| language.matches(path))
+    }
+
+    #[must_use]
+    #[doc = "Check if a file marks a project of the language, by name or by unambiguous extension"]
+    pub fn detected(&self, path: &Path) -> bool {
+        let by_name: bool = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| self.markers().contains(&name));
+        let by_extension: bool = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extension.ne("h") && self.extensions().contains(&extension));
+        by_name || by_extension
+    }
+
+    #[must_use]
+    #[doc = "Check if a file belongs to the language"]
+    pub fn matches(&self, path: &Path) -> bool {
+        let by_name: bool = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| self.files().contains(&name));
+        let by_extension: bool = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| self.extensions().contains(&extension));
+        by_name || by_extension
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[doc = "What zuu knows about a supported language"]
+pub struct Metadata {
+    pub language: Language,
+    #[doc = "The source file extensions, without the dot"]
+    pub extensions: &'static [&'static str],
+    #[doc = "The project files, like manifests and lockfiles"]
+    pub files: &'static [&'static str],
+    #[doc = "The files marking a project of the language"]
+    pub markers: &'static [&'static str],
+    #[doc = "The configuration files of its tools, `*` matching any suffix"]
+    pub configs: &'static [&'static str],
+    #[doc = "The build systems and package managers commonly used"]
+    pub build_systems: &'static [&'static str],
+    #[doc = "The external programs called by the built-in tasks"]
+    pub tools: Vec<String>,
 }
 
 #[derive(Default)]
+#[doc = "The registry of the supported languages with their metadata"]
 pub struct Support {
-    pub languages: Vec<Language>,
+    pub languages: Vec<Metadata>,
 }
 impl Support {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            languages: vec![
-                Language::Rust,
-                Language::JavaScript,
-                Language::TypeScript,
-                Language::Go,
-                Language::D,
-                Language::Python,
-                Language::Php,
-                Language::Java,
-                Language::Kotlin,
-                Language::Swift,
-                Language::Scala,
-                Language::Ruby,
-                Language::Perl,
-                Language::R,
-                Language::Haskell,
-                Language::Lua,
-                Language::ObjectiveC,
-                Language::C,
-                Language::Cpp,
-                Language::Nim,
-                Language::Crystal,
-                Language::FSharp,
-                Language::Dart,
-                Language::Elixir,
-                Language::Bash,
-                Language::Zsh,
-                Language::Fish,
-            ],
+            languages: Language::all().map(Language::metadata).to_vec(),
         }
     }
 
     #[must_use]
-    #[doc = "Get all supported languages"]
+    #[doc = "Get the names of all supported languages"]
     pub fn all(self) -> Vec<String> {
-        let mut data: Vec<String> = Vec::new();
-        for language in &self.languages {
-            match language {
-                Language::Rust => data.push(String::from("Rust")),
-                Language::JavaScript => data.push(String::from("Javascript")),
-                Language::TypeScript => data.push(String::from("TypeScript")),
-                Language::Go => data.push(String::from("Go")),
-                Language::D => data.push(String::from("D")),
-                Language::Python => data.push(String::from("Python")),
-                Language::Php => data.push(String::from("Php")),
-                Language::Java => data.push(String::from("Java")),
-                Language::Kotlin => data.push(String::from("Kotlin")),
-                Language::Swift => data.push(String::from("Swift")),
-                Language::Scala => data.push(String::from("Scala")),
-                Language::Ruby => data.push(String::from("Ruby")),
-                Language::Perl => data.push(String::from("Perl")),
-                Language::R => data.push(String::from("R")),
-                Language::Haskell => data.push(String::from("Haskell")),
-                Language::Lua => data.push(String::from("Lua")),
-                Language::ObjectiveC => data.push(String::from("ObjectiveC")),
-                Language::C => data.push(String::from("C")),
-                Language::Cpp => data.push(String::from("Cpp")),
-                Language::Nim => data.push(String::from("Nim")),
-                Language::Crystal => data.push(String::from("Crystal")),
-                Language::FSharp => data.push(String::from("Fsharp")),
-                Language::Dart => data.push(String::from("Dart")),
-                Language::Elixir => data.push(String::from("Elixir")),
-                Language::Bash => data.push(String::from("Bash")),
-                Language::Zsh => data.push(String::from("Zsh")),
-                Language::Fish => data.push(String::from("Fish")),
-                Language::Unknown => continue,
-            }
-        }
-        data
+        self.languages
+            .iter()
+            .map(|entry| entry.language.to_string())
+            .collect()
     }
+
     #[must_use]
     #[doc = "Get all supported language"]
     pub fn supported(self) -> Vec<Language> {
+        self.languages.iter().map(|entry| entry.language).collect()
+    }
+
+    #[must_use]
+    #[doc = "Get the metadata of a supported language"]
+    pub fn get(&self, language: Language) -> Option<&Metadata> {
         self.languages
+            .iter()
+            .find(|entry| entry.language.eq(&language))
+    }
+
+    ///
+    /// # Detect
+    ///
+    /// Walk the project tree, honoring the `.gitignore` rules, and get the languages
+    /// having a marker file like `Cargo.toml` or a source file
+    ///
+    #[must_use]
+    pub fn detect(root: &Path) -> Vec<Language> {
+        let mut remaining: Vec<Language> = Self::new().supported();
+        let mut found: Vec<Language> = Vec::new();
+        for path in walk(root) {
+            if remaining.is_empty() {
+                break;
+            }
+            remaining.retain(|language| {
+                if language.detected(&path) {
+                    found.push(*language);
+                    return false;
+                }
+                true
+            });
+        }
+        Self::new()
+            .supported()
+            .into_iter()
+            .filter(|language| found.contains(language))
+            .collect()
+    }
+}
+
+#[doc = "The directories holding the tests and their fixtures"]
+pub const FIXTURES: [&str; 6] = ["tests", "test", "__tests__", "spec", "fixtures", "testdata"];
+
+#[must_use]
+#[doc = "Check if a file is in a test or fixture directory"]
+pub fn fixture(path: &Path) -> bool {
+    path.parent().is_some_and(|dir| {
+        dir.components()
+            .any(|c| FIXTURES.iter().any(|f| c.as_os_str().eq(*f)))
+    })
+}
+
+#[doc = "Walk the files of a project tree, honoring the `.gitignore` rules and skipping the ignored directories"]
+pub fn walk(root: &Path) -> impl Iterator<Item = PathBuf> {
+    walker(root, true)
+}
+
+#[doc = "Walk every file of a project tree like `walk`, hidden ones like `.eslintrc` included, but the git directory"]
+pub fn tree(root: &Path) -> impl Iterator<Item = PathBuf> {
+    walker(root, false)
+}
+
+#[doc = "Walk the files of a project tree, with or without the hidden ones"]
+fn walker(root: &Path, hidden: bool) -> impl Iterator<Item = PathBuf> {
+    WalkBuilder::new(root)
+        .hidden(hidden)
+        .require_git(false)
+        .filter_entry(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_none_or(|name| !IGNORED.contains(&name) && name.ne(".git"))
+        })
+        .build()
+        .flatten()
+        .map(ignore::DirEntry::into_path)
+        .filter(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_aliases_parse_without_case() {
+        for (name, language) in [
+            ("rust", Language::Rust),
+            ("js", Language::JavaScript),
+            ("NodeJS", Language::JavaScript),
+            ("ts", Language::TypeScript),
+            ("TypeScript", Language::TypeScript),
+            ("c++", Language::Cpp),
+            ("C++", Language::Cpp),
+            ("f#", Language::FSharp),
+            ("F#", Language::FSharp),
+            ("Objective-C", Language::ObjectiveC),
+            (" golang ", Language::Go),
+            ("PYTHON", Language::Python),
+        ] {
+            assert_eq!(name.parse::<Language>().ok(), Some(language), "{name}");
+        }
+    }
+
+    #[test]
+    fn unknown_names_are_rejected() {
+        for name in ["cobol", "", "c+", "rustlang"] {
+            assert!(matches!(
+                name.parse::<Language>(),
+                Err(Error::UnknownLanguage { name: unknown }) if unknown.eq(name)
+            ));
+        }
+    }
+
+    #[test]
+    fn serde_writes_the_canonical_name() {
+        let languages: Vec<Language> = serde_json::from_str(r#"["ts", "C++", "f#"]"#).unwrap();
+        assert_eq!(
+            languages,
+            [Language::TypeScript, Language::Cpp, Language::FSharp]
+        );
+        let json: String = serde_json::to_string(&languages).unwrap();
+        assert_eq!(json, r#"["TypeScript","Cpp","FSharp"]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Language>>(json.as_str()).unwrap(),
+            languages
+        );
+        for language in Language::all() {
+            let name: String = serde_json::to_string(&language).unwrap();
+            assert_eq!(serde_json::from_str::<Language>(&name).unwrap(), language);
+        }
+        assert!(serde_json::from_str::<Language>(r#""cobol""#).is_err());
+    }
+
+    #[test]
+    fn from_path_finds_the_language_of_a_file() {
+        for (path, language) in [
+            ("src/main.rs", Some(Language::Rust)),
+            ("Cargo.lock", Some(Language::Rust)),
+            ("include/list.h", Some(Language::C)),
+            ("src/list.c", Some(Language::C)),
+            ("src/list.cpp", Some(Language::Cpp)),
+            ("package.json", Some(Language::JavaScript)),
+            ("src/app.ts", Some(Language::TypeScript)),
+            ("README.md", None),
+            ("Makefile", Some(Language::C)),
+            ("docs/notes.txt", None),
+        ] {
+            assert_eq!(Language::from_path(Path::new(path)), language, "{path}");
+        }
+        assert!(!Language::Cpp.detected(Path::new("include/list.h")));
+        assert!(Language::Cpp.matches(Path::new("include/list.h")));
+    }
+
+    #[test]
+    fn configs_and_fixtures_are_recognized() {
+        assert!(Language::Rust.configured(Path::new("deny.toml")));
+        assert!(Language::Rust.configured(Path::new(".cargo/config.toml")));
+        assert!(Language::JavaScript.configured(Path::new(".eslintrc.json")));
+        assert!(Language::TypeScript.configured(Path::new("tsconfig.build.json")));
+        assert!(!Language::TypeScript.configured(Path::new("tsconfig.yaml")));
+        assert!(!Language::Go.configured(Path::new("deny.toml")));
+        assert!(fixture(Path::new("tests/data/input.txt")));
+        assert!(fixture(Path::new("crates/api/testdata/golden.json")));
+        assert!(!fixture(Path::new("src/tests.rs")));
+        assert!(!fixture(Path::new("README.md")));
+    }
+
+    #[test]
+    fn detect_and_walk_skip_the_ignored_directories() {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-walk-{}", std::process::id()));
+        for (file, content) in [
+            ("Cargo.toml", ""),
+            (".gitignore", "generated/\n"),
+            (".github/workflows/ci.yml", ""),
+            ("src/main.rs", ""),
+            ("node_modules/left-pad/index.js", ""),
+            ("target/debug/build.go", ""),
+            ("zuu/stdout/app.py", ""),
+            (".git/hooks/pre-commit.rb", ""),
+            ("generated/api.php", ""),
+        ] {
+            let path: PathBuf = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let detected: Vec<Language> = Support::detect(&root);
+        let mut walked: Vec<PathBuf> = walk(&root)
+            .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+        let mut all: Vec<PathBuf> = tree(&root)
+            .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+        std::fs::remove_dir_all(&root).unwrap();
+        walked.sort();
+        all.sort();
+        assert_eq!(detected, [Language::Rust]);
+        assert_eq!(
+            walked,
+            [PathBuf::from("Cargo.toml"), PathBuf::from("src/main.rs")]
+        );
+        assert_eq!(
+            all,
+            [
+                PathBuf::from(".github/workflows/ci.yml"),
+                PathBuf::from(".gitignore"),
+                PathBuf::from("Cargo.toml"),
+                PathBuf::from("src/main.rs"),
+            ]
+        );
     }
 }
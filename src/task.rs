@@ -0,0 +1,567 @@
+use crate::ask::TaskStatus;
+use crate::command::{needs_shell, split};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::read,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+#[doc = "The number of output lines kept in the report of a task"]
+pub const TAIL: usize = 5;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[serde(rename_all = "lowercase")]
+#[doc = "The kind of check performed by a task"]
+pub enum Category {
+    Structure,
+    License,
+    Deps,
+    Audit,
+    Test,
+    Format,
+    Doc,
+    Outdated,
+    Lint,
+    Types,
+    Custom,
+}
+
+impl Category {
+    #[must_use]
+    #[doc = "Get all task categories"]
+    pub const fn all() -> [Self; 11] {
+        [
+            Self::Structure,
+            Self::License,
+            Self::Deps,
+            Self::Audit,
+            Self::Test,
+            Self::Format,
+            Self::Doc,
+            Self::Outdated,
+            Self::Lint,
+            Self::Types,
+            Self::Custom,
+        ]
+    }
+
+    #[must_use]
+    #[doc = "Get the name used in tux.toml for the category"]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Structure => "structure",
+            Self::License => "license",
+            Self::Deps => "deps",
+            Self::Audit => "audit",
+            Self::Test => "test",
+            Self::Format => "format",
+            Self::Doc => "doc",
+            Self::Outdated => "outdated",
+            Self::Lint => "lint",
+            Self::Types => "types",
+            Self::Custom => "custom",
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the log file name of the category"]
+    pub const fn output_file(self) -> &'static str {
+        match self {
+            Self::Structure => "project_validation.txt",
+            Self::License => "license_check.txt",
+            Self::Deps => "dependency_checks.txt",
+            Self::Audit => "security_audit.txt",
+            Self::Test => "test_results.txt",
+            Self::Format => "formatting_check.txt",
+            Self::Doc => "documentation_generation.txt",
+            Self::Outdated => "dependency_updates.txt",
+            Self::Lint => "code_linting.txt",
+            Self::Types => "type_checking.txt",
+            Self::Custom => "custom.txt",
+        }
+    }
+
+    #[must_use]
+    #[doc = "Check if the category is fast enough to run first in watch mode"]
+    pub const fn fast(self) -> bool {
+        matches!(self, Self::Format | Self::Lint)
+    }
+
+    #[must_use]
+    #[doc = "Find a category by its tux.toml name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|c| c.name().eq(name))
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[doc = "The environment variables enabling or disabling a task category"]
+pub const TOGGLES: [(&str, Category); 5] = [
+    ("TESTS", Category::Test),
+    ("FORMAT", Category::Format),
+    ("LINT", Category::Lint),
+    ("AUDIT", Category::Audit),
+    ("LICENSE", Category::License),
+];
+
+#[derive(Default, Clone, Debug)]
+#[doc = "A layer of the selection, by task or category name"]
+pub struct Layer {
+    pub only: Vec<String>,
+    #[doc = "The tasks enabled without excluding the other ones, like `TESTS=true`"]
+    pub enable: Vec<String>,
+    pub skip: Vec<String>,
+}
+
+impl Layer {
+    #[doc = "Decide if a task runs, `None` when the layer does not mention it and has no `only` list"]
+    fn decide(&self, task: &Task) -> Option<bool> {
+        let matches = |name: &String| name.eq(&task.name) || name.eq(task.category.name());
+        if self.skip.iter().any(matches) {
+            return Some(false);
+        }
+        if self.enable.iter().any(matches) {
+            return Some(true);
+        }
+        (!self.only.is_empty()).then(|| self.only.iter().any(matches))
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+#[doc = "The tasks selected to run, by layers: tux.toml, then the environment, then the command line"]
+pub struct Selection {
+    pub layers: Vec<Layer>,
+}
+
+impl Selection {
+    #[must_use]
+    #[doc = "A selection of a single layer, like the one of tux.toml"]
+    pub fn new(only: &[String], skip: &[String]) -> Self {
+        Self::default().with(only, skip)
+    }
+
+    #[must_use]
+    #[doc = "Add a layer overriding the previous ones, like the `--only` and `--skip` options"]
+    pub fn with(mut self, only: &[String], skip: &[String]) -> Self {
+        self.layers.push(Layer {
+            only: only.to_vec(),
+            enable: Vec::new(),
+            skip: skip.to_vec(),
+        });
+        self
+    }
+
+    ///
+    /// # Environment
+    ///
+    /// Add a layer with the `TESTS`, `FORMAT`, `LINT`, `AUDIT` and `LICENSE` toggles.
+    ///
+    /// A toggle set to false skips its category, set to true it runs it even when tux.toml skips it
+    /// or selects other tasks only.
+    ///
+    #[must_use]
+    pub fn with_env(mut self) -> Self {
+        let mut layer: Layer = Layer::default();
+        for (var, category) in TOGGLES {
+            match std::env::var(var)
+                .map(|v| v.trim().to_lowercase())
+                .as_deref()
+            {
+                Ok("false" | "0" | "no" | "off") => layer.skip.push(category.name().to_string()),
+                Ok("true" | "1" | "yes" | "on") => layer.enable.push(category.name().to_string()),
+                _ => {}
+            }
+        }
+        self.layers.push(layer);
+        self
+    }
+
+    ///
+    /// # Enabled
+    ///
+    /// Check if a task must run.
+    ///
+    /// The last layer deciding wins, so `--only test` runs the tests disabled by `TESTS=false`.
+    ///
+    #[must_use]
+    pub fn enabled(&self, task: &Task) -> bool {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.decide(task))
+            .unwrap_or(true)
+    }
+}
+
+#[doc = "A built-in task definition usable in const tables"]
+pub struct BuiltinTask {
+    pub name: &'static str,
+    pub category: Category,
+    pub description: &'static str,
+    pub command: &'static str,
+    pub success: &'static str,
+    pub failure: &'static str,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "lowercase")]
+#[doc = "Where the command of a task comes from"]
+pub enum Provenance {
+    #[default]
+    #[doc = "The tables shipped with zuu"]
+    Builtin,
+    #[doc = "A provider of the plugin directory, installed by the user"]
+    Plugin,
+    #[doc = "The tux.toml of the checked project"]
+    Project,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[doc = "A task to execute"]
+pub struct Task {
+    pub name: String,
+    pub category: Category,
+    pub description: String,
+    pub command: String,
+    pub success: String,
+    pub failure: String,
+    pub directory: Option<PathBuf>,
+    pub env: BTreeMap<String, String>,
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    #[doc = "Run the command through `sh -c` instead of splitting it in arguments"]
+    pub shell: bool,
+    #[serde(default)]
+    pub provenance: Provenance,
+}
+
+impl Task {
+    #[must_use]
+    pub fn new(name: &str, category: Category, command: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            category,
+            description: name.to_string(),
+            command: command.to_string(),
+            success: format!("{name} passed"),
+            failure: format!("{name} failed"),
+            directory: None,
+            env: BTreeMap::new(),
+            timeout: None,
+            shell: false,
+            provenance: Provenance::Builtin,
+        }
+    }
+
+    #[must_use]
+    #[doc = "Get the log file name of the task"]
+    pub fn output_file(&self) -> String {
+        if self.category.ne(&Category::Custom) && self.name.eq(self.category.name()) {
+            return self.category.output_file().to_string();
+        }
+        format!("{}.txt", self.name)
+    }
+
+    #[doc = "Apply the task working directory and environment to a command"]
+    pub fn prepare<'a>(&self, cmd: &'a mut Command) -> &'a mut Command {
+        if let Some(directory) = &self.directory {
+            cmd.current_dir(directory);
+        }
+        cmd.envs(&self.env)
+    }
+
+    ///
+    /// # Argv
+    ///
+    /// Split the command in the program and its arguments, without any shell expansion
+    ///
+    /// # Errors
+    ///
+    /// `Command` when the command is empty, has an unterminated quote or needs a shell
+    ///
+    pub fn argv(&self) -> Result<Vec<String>> {
+        split(self.command.as_str()).map_err(|message| Error::Command {
+            task: self.name.clone(),
+            message,
+        })
+    }
+
+    ///
+    /// # Check
+    ///
+    /// Refuse a task which can't run safely.
+    ///
+    /// A shell task of tux.toml runs only once the file is trusted, and a task without
+    /// a shell must split in arguments.
+    ///
+    /// # Errors
+    ///
+    /// `Untrusted` for a shell task of an untrusted tux.toml, `Command` for an invalid command
+    ///
+    pub fn check(&self, language: &str, trusted: bool) -> Result<()> {
+        if self.shell {
+            if self.provenance.eq(&Provenance::Project) && !trusted {
+                return Err(Error::Untrusted {
+                    task: format!("{language}/{}", self.name),
+                });
+            }
+            return Ok(());
+        }
+        split(self.command.as_str())
+            .map(|_| ())
+            .map_err(|message| Error::Command {
+                task: format!("{language}/{}", self.name),
+                message,
+            })
+    }
+}
+
+impl From<&BuiltinTask> for Task {
+    fn from(task: &BuiltinTask) -> Self {
+        Self {
+            name: task.name.to_string(),
+            category: task.category,
+            description: task.description.to_string(),
+            command: task.command.to_string(),
+            success: task.success.to_string(),
+            failure: task.failure.to_string(),
+            directory: None,
+            env: BTreeMap::new(),
+            timeout: None,
+            shell: needs_shell(task.command),
+            provenance: Provenance::Builtin,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+#[doc = "How a task process ended"]
+pub struct Exit {
+    #[doc = "The exit code, `None` when killed by a signal"]
+    pub code: Option<i32>,
+    #[doc = "The signal which killed the process"]
+    pub signal: Option<i32>,
+    pub duration: Duration,
+    #[doc = "Killed after its timeout"]
+    pub timed_out: bool,
+    #[doc = "Killed by Ctrl-C"]
+    pub interrupted: bool,
+}
+
+impl Exit {
+    ///
+    /// # Result
+    ///
+    /// Check if the task passed
+    ///
+    /// # Errors
+    ///
+    /// `Interrupted`, `Timeout` or `NonZeroExit` with the exit code
+    ///
+    pub fn result(&self) -> Result<()> {
+        if self.interrupted {
+            return Err(Error::Interrupted);
+        }
+        if self.timed_out {
+            return Err(Error::Timeout);
+        }
+        if self.code.eq(&Some(0)) {
+            return Ok(());
+        }
+        Err(Error::NonZeroExit { code: self.code })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[doc = "The result of an executed task"]
+pub struct TaskResult {
+    pub name: String,
+    pub category: Category,
+    pub description: String,
+    pub command: String,
+    #[doc = "Killed after its timeout"]
+    pub timed_out: bool,
+    #[doc = "The exit code, `None` when killed by a signal or not run"]
+    pub code: Option<i32>,
+    #[doc = "The signal which killed the task"]
+    pub signal: Option<i32>,
+    #[doc = "The wall-clock duration in seconds"]
+    pub duration: f64,
+    pub stdout: PathBuf,
+    pub stderr: PathBuf,
+    #[doc = "The last lines of stderr, or of stdout when stderr is empty"]
+    pub tail: Vec<String>,
+    #[serde(default)]
+    #[doc = "Reused from the cache, the inputs of the task being unchanged"]
+    pub cached: bool,
+    #[serde(default)]
+    #[doc = "The program of the task missing from the PATH"]
+    pub missing: Option<String>,
+    #[serde(default)]
+    pub status: TaskStatus,
+}
+
+impl TaskResult {
+    #[must_use]
+    pub fn new(task: &Task, exit: &Exit, directory: &Path) -> Self {
+        let output_file: String = task.output_file();
+        let stdout: PathBuf = directory.join("stdout").join(&output_file);
+        let stderr: PathBuf = directory.join("stderr").join(&output_file);
+        let tail: Vec<String> = match tail(&stderr) {
+            lines if lines.is_empty() => tail(&stdout),
+            lines => lines,
+        };
+        let status: TaskStatus = match exit.result() {
+            Ok(()) => TaskStatus::Pass,
+            Err(Error::Interrupted) => TaskStatus::NotRun,
+            Err(Error::Timeout) => TaskStatus::Timeout,
+            Err(_) if exit.signal.is_some() => TaskStatus::Error,
+            Err(_) => TaskStatus::Fail,
+        };
+        Self {
+            name: task.name.clone(),
+            category: task.category,
+            description: task.description.clone(),
+            command: task.command.clone(),
+            timed_out: exit.timed_out,
+            code: exit.code,
+            signal: exit.signal,
+            duration: exit.duration.as_secs_f64(),
+            stdout,
+            stderr,
+            tail,
+            cached: false,
+            missing: None,
+            status,
+        }
+    }
+
+    #[must_use]
+    #[doc = "The result of a task whose program is not installed"]
+    pub fn not_installed(task: &Task, program: &str, directory: &Path) -> Self {
+        Self {
+            tail: vec![format!("{program}: not installed")],
+            missing: Some(program.to_string()),
+            status: TaskStatus::Error,
+            ..Self::new(task, &Exit::default(), directory)
+        }
+    }
+
+    #[must_use]
+    #[doc = "The result of a task which could not be started, with the reason"]
+    pub fn error(task: &Task, reason: &str, directory: &Path) -> Self {
+        Self {
+            tail: vec![reason.to_string()],
+            status: TaskStatus::Error,
+            ..Self::new(task, &Exit::default(), directory)
+        }
+    }
+
+    #[must_use]
+    #[doc = "The result of a task never reached, after a failure in strict mode or a Ctrl-C"]
+    pub fn not_run(task: &Task, directory: &Path) -> Self {
+        Self {
+            tail: Vec::new(),
+            status: TaskStatus::NotRun,
+            ..Self::new(task, &Exit::default(), directory)
+        }
+    }
+
+    #[must_use]
+    #[doc = "The result of a task disabled by the selection"]
+    pub fn skipped(task: &Task, directory: &Path) -> Self {
+        Self {
+            tail: Vec::new(),
+            status: TaskStatus::Skipped,
+            ..Self::new(task, &Exit::default(), directory)
+        }
+    }
+}
+
+#[doc = "Read the last non empty lines of a log file"]
+fn tail(path: &Path) -> Vec<String> {
+    let Ok(bytes) = read(path) else {
+        return Vec::new();
+    };
+    let content: String = String::from_utf8_lossy(&bytes).to_string();
+    let lines: Vec<String> = content
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .map(String::from)
+        .collect();
+    lines[lines.len().saturating_sub(TAIL)..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(name: &str, category: Category) -> Task {
+        Task::new(name, category, "true")
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    fn env(enable: &[&str], skip: &[&str]) -> Layer {
+        Layer {
+            only: Vec::new(),
+            enable: names(enable),
+            skip: names(skip),
+        }
+    }
+
+    #[test]
+    fn command_line_only_overrides_an_env_skip() {
+        let mut selection: Selection = Selection::new(&[], &[]);
+        selection.layers.push(env(&[], &["test"]));
+        let selection: Selection = selection.with(&names(&["test"]), &[]);
+        assert!(selection.enabled(&task("test", Category::Test)));
+        assert!(!selection.enabled(&task("lint", Category::Lint)));
+    }
+
+    #[test]
+    fn env_enable_overrides_a_toml_only() {
+        let mut selection: Selection = Selection::new(&names(&["lint"]), &[]);
+        selection.layers.push(env(&["test"], &[]));
+        assert!(selection.enabled(&task("test", Category::Test)));
+        assert!(selection.enabled(&task("lint", Category::Lint)));
+        assert!(!selection.enabled(&task("doc", Category::Doc)));
+    }
+
+    #[test]
+    fn env_enable_overrides_a_toml_skip() {
+        let mut selection: Selection = Selection::new(&[], &names(&["test"]));
+        selection.layers.push(env(&["test"], &[]));
+        assert!(selection.enabled(&task("test", Category::Test)));
+    }
+
+    #[test]
+    fn command_line_task_overrides_a_skipped_category() {
+        let selection: Selection =
+            Selection::new(&[], &names(&["lint"])).with(&names(&["clippy"]), &[]);
+        assert!(selection.enabled(&task("clippy", Category::Lint)));
+        assert!(!selection.enabled(&task("fmt", Category::Lint)));
+    }
+
+    #[test]
+    fn command_line_skip_wins() {
+        let mut selection: Selection = Selection::new(&[], &[]);
+        selection.layers.push(env(&["test"], &[]));
+        let selection: Selection = selection.with(&[], &names(&["test"]));
+        assert!(!selection.enabled(&task("test", Category::Test)));
+        assert!(selection.enabled(&task("lint", Category::Lint)));
+    }
+}
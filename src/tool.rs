@@ -0,0 +1,375 @@
+use crate::task::Task;
+use cli_table::Table;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::canonicalize,
+    path::{absolute, Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+#[doc = "The status of an installed tool"]
+pub const INSTALLED: &str = "INSTALLED";
+
+#[doc = "The status of a tool missing from the PATH"]
+pub const NOT_INSTALLED: &str = "NOT INSTALLED";
+
+#[doc = "The exit code of sh when a command is not found"]
+pub const NOT_FOUND: i32 = 127;
+
+#[doc = "The shell builtins and keywords, never searched in the PATH"]
+pub const BUILTINS: [&str; 18] = [
+    ":", ".", "[", "cd", "command", "echo", "eval", "exec", "exit", "export", "false", "if",
+    "printf", "set", "source", "test", "true", "unset",
+];
+
+#[doc = "The cargo subcommands shipped with cargo, the other ones are `cargo-<name>` programs"]
+pub const CARGO: [&str; 32] = [
+    "add",
+    "bench",
+    "build",
+    "check",
+    "clean",
+    "doc",
+    "fetch",
+    "fix",
+    "generate-lockfile",
+    "help",
+    "info",
+    "init",
+    "install",
+    "locate-project",
+    "login",
+    "logout",
+    "metadata",
+    "new",
+    "owner",
+    "package",
+    "pkgid",
+    "publish",
+    "remove",
+    "report",
+    "run",
+    "rustc",
+    "rustdoc",
+    "search",
+    "test",
+    "tree",
+    "update",
+    "verify-project",
+];
+
+#[doc = "The install hints of the tools called by the built-in tasks"]
+pub const HINTS: [(&str, &str); 39] = [
+    ("cargo-audit", "cargo install cargo-audit"),
+    ("cargo-clippy", "rustup component add clippy"),
+    ("cargo-deny", "cargo install cargo-deny"),
+    ("cargo-fmt", "rustup component add rustfmt"),
+    ("cargo-outdated", "cargo install cargo-outdated"),
+    (
+        "golangci-lint",
+        "go install github.com/golangci/golangci-lint/cmd/golangci-lint@latest",
+    ),
+    ("gofmt", "install Go from https://go.dev/dl"),
+    ("go", "install Go from https://go.dev/dl"),
+    ("npm", "install Node.js from https://nodejs.org"),
+    ("npx", "install Node.js from https://nodejs.org"),
+    ("pnpm", "corepack enable pnpm"),
+    ("yarn", "corepack enable yarn"),
+    ("bun", "install Bun from https://bun.sh"),
+    ("pytest", "pip install pytest"),
+    ("black", "pip install black"),
+    ("flake8", "pip install flake8"),
+    ("mypy", "pip install mypy"),
+    ("bandit", "pip install bandit"),
+    ("sphinx-build", "pip install sphinx"),
+    ("composer", "install Composer from https://getcomposer.org"),
+    ("bundle", "gem install bundler"),
+    ("yard", "gem install yard"),
+    ("swiftlint", "brew install swiftlint"),
+    ("swiftformat", "brew install swiftformat"),
+    (
+        "shellcheck",
+        "apt install shellcheck, or brew install shellcheck",
+    ),
+    ("luacheck", "luarocks install luacheck"),
+    ("busted", "luarocks install busted"),
+    ("ldoc", "luarocks install ldoc"),
+    ("perlcritic", "cpan Perl::Critic"),
+    ("perltidy", "cpan Perl::Tidy"),
+    ("cpan-outdated", "cpan App::cpanoutdated"),
+    ("hlint", "stack install hlint"),
+    ("hindent", "stack install hindent"),
+    ("cppcheck", "apt install cppcheck, or brew install cppcheck"),
+    (
+        "clang-format",
+        "apt install clang-format, or brew install clang-format",
+    ),
+    ("clang-tidy", "apt install clang-tidy, or brew install llvm"),
+    ("doxygen", "apt install doxygen, or brew install doxygen"),
+    ("scalafmt", "coursier install scalafmt"),
+    ("Rscript", "install R from https://cran.r-project.org"),
+];
+
+#[derive(Table, Serialize)]
+#[doc = "The availability of the program of a task"]
+pub struct Tool {
+    #[table(title = "LANGUAGE")]
+    pub language: String,
+    #[table(title = "TASK")]
+    pub task: String,
+    #[table(title = "PROGRAM")]
+    pub program: String,
+    #[table(title = "STATUS")]
+    pub status: String,
+    #[table(title = "VERSION")]
+    pub version: String,
+    #[table(title = "PATH OR INSTALL HINT")]
+    pub hint: String,
+}
+
+///
+/// # Program
+///
+/// Get the program run by a command, skipping the leading variable assignments.
+///
+/// A cargo subcommand not shipped with cargo, like `cargo outdated`, is its `cargo-outdated` program.
+/// The shell builtins have no program.
+///
+#[must_use]
+pub fn program(command: &str) -> Option<String> {
+    named(command.split_whitespace())
+}
+
+///
+/// # Task program
+///
+/// Get the program started by a task, from its arguments unless it runs through a shell, so a
+/// quoted program like `'./my tool'` keeps its spaces.
+///
+#[must_use]
+pub fn task_program(task: &Task) -> Option<String> {
+    if task.shell {
+        return program(task.command.as_str());
+    }
+    let argv: Vec<String> = task.argv().ok()?;
+    named(argv.iter().map(String::as_str))
+}
+
+#[doc = "Get the program run by the words of a command"]
+fn named<'a>(words: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut words = words.skip_while(|word| word.contains('=') && !word.starts_with('='));
+    let name: &str = words.next()?;
+    if BUILTINS.contains(&name) {
+        return None;
+    }
+    if name.eq("cargo") {
+        if let Some(sub) = words.find(|word| !word.starts_with('+') && !word.starts_with('-')) {
+            if !CARGO.contains(&sub) {
+                return Some(format!("cargo-{sub}"));
+            }
+        }
+    }
+    Some(name.to_string())
+}
+
+#[must_use]
+#[doc = "Find an executable program in the PATH, or check its path when it contains a slash"]
+pub fn which(program: &str) -> Option<PathBuf> {
+    if program.contains('/') {
+        let path: PathBuf = PathBuf::from(program);
+        return executable(&path).then_some(path);
+    }
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join(program))
+        .find(|path| executable(path))
+}
+
+#[cfg(unix)]
+#[doc = "Check if a path is a file with an execute permission"]
+fn executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+#[doc = "Check if a path is a file"]
+fn executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+///
+/// # Locate
+///
+/// Get the path of a program started in a directory.
+///
+/// A relative path containing a slash is made absolute from the directory, so the program does
+/// not depend on how the platform resolves it once the working directory changed.
+///
+#[must_use]
+pub fn locate(program: &str, directory: &Path) -> PathBuf {
+    if !program.contains('/') || Path::new(program).is_absolute() {
+        return PathBuf::from(program);
+    }
+    let joined: PathBuf = directory.join(program);
+    canonicalize(&joined)
+        .or_else(|_| absolute(&joined))
+        .unwrap_or(joined)
+}
+
+#[must_use]
+#[doc = "Find the executable of a program started in a directory, see `locate` and `which`"]
+pub fn resolve(program: &str, directory: &Path) -> Option<PathBuf> {
+    which(locate(program, directory).to_str()?)
+}
+
+#[must_use]
+#[doc = "Get the program of a task run in a directory missing from the PATH, or from the directory"]
+pub fn missing(task: &Task, directory: &Path) -> Option<String> {
+    task_program(task).filter(|program| {
+        if program.contains('/') {
+            return !locate(program, directory).is_file();
+        }
+        which(program).is_none()
+    })
+}
+
+///
+/// # Not found
+///
+/// Get the command reported missing by the shell in the output of a task,
+/// like `sh: 1: foo: not found` or `bash: foo: command not found`
+///
+#[must_use]
+pub fn not_found(lines: &[String]) -> Option<String> {
+    lines.iter().rev().find_map(|line| {
+        let parts: Vec<&str> = line.trim().split(": ").collect();
+        match parts.as_slice() {
+            [.., name, "not found" | "command not found"] if !name.trim().is_empty() => {
+                Some(name.trim().to_string())
+            }
+            _ => None,
+        }
+    })
+}
+
+#[must_use]
+#[doc = "Get the first line printed by `<program> --version` run in a directory, honoring its toolchain files"]
+pub fn version(program: &str, directory: &Path) -> Option<String> {
+    let (name, args): (&str, Vec<&str>) = match program.strip_prefix("cargo-") {
+        Some(sub) => ("cargo", vec![sub, "--version"]),
+        None => (program, vec!["--version"]),
+    };
+    let output = Command::new(name)
+        .args(args)
+        .current_dir(directory)
+        .stdin(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .chain(String::from_utf8_lossy(&output.stderr).lines())
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(String::from)
+}
+
+#[must_use]
+#[doc = "Get the install hint of a program"]
+pub fn hint(program: &str) -> String {
+    HINTS
+        .iter()
+        .find(|(name, _)| name.eq(&program))
+        .map_or_else(
+            || format!("install {program} and add it to the PATH"),
+            |(_, hint)| (*hint).to_string(),
+        )
+}
+
+///
+/// # Doctor
+///
+/// Resolve the program of every task in the PATH, or from the task directory when it is a path,
+/// with its version when installed and its install hint otherwise.
+///
+/// Every program is asked for its version once per directory, where its toolchain files apply.
+///
+#[must_use]
+pub fn doctor(tasks: &[(String, Vec<Task>)]) -> Vec<Tool> {
+    let mut versions: BTreeMap<(PathBuf, PathBuf), String> = BTreeMap::new();
+    let mut tools: Vec<Tool> = Vec::new();
+    for (language, tasks) in tasks {
+        for task in tasks {
+            let Some(program) = task_program(task) else {
+                continue;
+            };
+            let directory: PathBuf = task.directory.clone().unwrap_or_else(|| PathBuf::from("."));
+            let resolved: Option<PathBuf> = resolve(&program, &directory);
+            let (status, version, hint): (&str, String, String) = match resolved {
+                Some(path) => (
+                    INSTALLED,
+                    versions
+                        .entry((path.clone(), directory))
+                        .or_insert_with_key(|(path, directory)| {
+                            let name: String = if program.contains('/') {
+                                path.display().to_string()
+                            } else {
+                                program.clone()
+                            };
+                            version(&name, directory).unwrap_or_default()
+                        })
+                        .clone(),
+                    path.display().to_string(),
+                ),
+                None => (NOT_INSTALLED, String::new(), hint(&program)),
+            };
+            tools.push(Tool {
+                language: language.clone(),
+                task: task.name.clone(),
+                program,
+                status: status.to_string(),
+                version,
+                hint,
+            });
+        }
+    }
+    tools
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::Category;
+    use std::{fs, process};
+
+    #[cfg(unix)]
+    #[test]
+    fn doctor_resolves_a_relative_program_in_its_project() {
+        use std::os::unix::fs::PermissionsExt;
+        let root: PathBuf = PathBuf::from(format!("target/zuu-doctor-{}", process::id()));
+        let api: PathBuf = root.join("api");
+        fs::create_dir_all(&api).unwrap();
+        let script: PathBuf = api.join("check.sh");
+        fs::write(&script, "#!/bin/sh\necho \"check 1.2.3 in ${PWD##*/}\"\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let mut check: Task = Task::new("check", Category::Test, "./check.sh --all");
+        check.directory = Some(api.clone());
+        let mut lint: Task = Task::new("lint", Category::Lint, "./lint.sh");
+        lint.directory = Some(api.clone());
+        let tools: Vec<Tool> = doctor(&[(String::from("api/Rust"), vec![check.clone(), lint])]);
+        let missing: Option<String> = missing(&check, &api);
+        let located: PathBuf = locate("./check.sh", &api);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(tools[0].program, "./check.sh");
+        assert_eq!(tools[0].status, INSTALLED);
+        assert_eq!(tools[0].version, "check 1.2.3 in api");
+        assert_eq!(tools[0].hint, located.display().to_string());
+        assert!(located.is_absolute());
+        assert_eq!(tools[1].status, NOT_INSTALLED);
+        assert_eq!(tools[1].hint, "install ./lint.sh and add it to the PATH");
+        assert_eq!(missing, None);
+    }
+}
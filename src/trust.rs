@@ -0,0 +1,73 @@
+use crate::error::Result;
+use std::{
+    env,
+    fs::{canonicalize, create_dir_all, read, write},
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+#[doc = "The directory of the approved tux.toml copies, relative to the user configuration directory"]
+pub const TRUSTED: &str = "tux/trusted";
+
+#[must_use]
+#[doc = "Get the user configuration directory, `$XDG_CONFIG_HOME` or `~/.config`"]
+pub fn config_home() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .filter(|config| !config.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+#[doc = "Get the path of the approved copy of a tux.toml, named by the hash of its canonical path"]
+fn approved(path: &Path) -> Option<PathBuf> {
+    let canonical: PathBuf = canonicalize(path).ok()?;
+    let mut hasher: DefaultHasher = DefaultHasher::new();
+    canonical.hash(&mut hasher);
+    Some(
+        config_home()?
+            .join(TRUSTED)
+            .join(format!("{:016x}.toml", hasher.finish())),
+    )
+}
+
+///
+/// # Trusted
+///
+/// Check if a tux.toml was approved with `tux trust` and is unchanged since.
+///
+/// Any edit of the file, like a pulled commit, withdraws the approval.
+///
+#[must_use]
+pub fn trusted(path: &Path) -> bool {
+    let Some(copy) = approved(path) else {
+        return false;
+    };
+    match (read(path), read(copy)) {
+        (Ok(current), Ok(copy)) => current.eq(&copy),
+        _ => false,
+    }
+}
+
+///
+/// # Trust
+///
+/// Approve the current content of a tux.toml, allowing its shell tasks to run
+///
+/// # Errors
+///
+/// `Io` when the file can't be read or the approval can't be written
+///
+pub fn trust(path: &Path) -> Result<PathBuf> {
+    let content: Vec<u8> = read(path)?;
+    let copy: PathBuf = approved(path).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "no user configuration directory, set HOME or XDG_CONFIG_HOME",
+        )
+    })?;
+    if let Some(parent) = copy.parent() {
+        create_dir_all(parent)?;
+    }
+    write(&copy, content)?;
+    Ok(copy)
+}
@@ -0,0 +1,214 @@
+use crate::error::{Error, Result};
+use crate::output::{interrupted, POLL};
+use crate::support::{Language, Support};
+use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
+    Match,
+};
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+#[doc = "The directories never watched, relative to the project root"]
+pub const IGNORED: [&str; 2] = ["zuu", ".git"];
+
+#[doc = "The delay waited after the last change before rerunning the checks"]
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[doc = "Watch the project tree for source changes"]
+pub struct Watch {
+    root: PathBuf,
+    events: Receiver<DebounceEventResult>,
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl Watch {
+    ///
+    /// # New
+    ///
+    /// Watch recursively the root directory, ignoring the paths matched by a `.gitignore` of the
+    /// root or of one of its subdirectories
+    ///
+    /// # Errors
+    ///
+    /// `Io` on failure to create the watcher
+    ///
+    pub fn new(root: &Path, delay: Duration) -> Result<Self> {
+        let root: PathBuf = root.canonicalize()?;
+        let (sender, events) = channel();
+        let mut debouncer =
+            new_debouncer(delay, sender).map_err(|e| Error::Io(std::io::Error::other(e)))?;
+        debouncer
+            .watcher()
+            .watch(root.as_path(), RecursiveMode::Recursive)
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+        Ok(Self {
+            root,
+            events,
+            _debouncer: debouncer,
+        })
+    }
+
+    #[must_use]
+    #[doc = "Check if a changed path must be ignored, see `ignored`"]
+    pub fn ignored(&self, path: &Path) -> bool {
+        ignored(&self.root, path)
+    }
+
+    ///
+    /// # Changes
+    ///
+    /// Wait for the next debounced batch of changed paths which are not ignored
+    ///
+    /// Return `None` when the watcher is stopped or the checkup interrupted
+    ///
+    #[must_use]
+    pub fn changes(&self) -> Option<Vec<PathBuf>> {
+        loop {
+            if interrupted() {
+                return None;
+            }
+            let events = match self.events.recv_timeout(POLL) {
+                Ok(Ok(events)) => events,
+                Ok(Err(_)) | Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return None,
+            };
+            let paths: Vec<PathBuf> = events
+                .into_iter()
+                .map(|event| event.path)
+                .filter(|path| !self.ignored(path))
+                .collect();
+            if !paths.is_empty() {
+                return Some(paths);
+            }
+        }
+    }
+}
+
+///
+/// # Ignored
+///
+/// Check if a path of the root must be ignored: outside the root, in an `IGNORED` directory, or
+/// matched by the `.gitignore` of one of its directories.
+///
+/// The files are read on every change, so an edited `.gitignore` applies at once. Like git, the
+/// deepest `.gitignore` matching the path decides, a `!pattern` keeping it.
+///
+#[must_use]
+pub fn ignored(root: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return true;
+    };
+    if relative
+        .components()
+        .next()
+        .and_then(|c| c.as_os_str().to_str())
+        .is_some_and(|c| IGNORED.contains(&c))
+    {
+        return true;
+    }
+    let directory: bool = path.is_dir();
+    for parent in relative.ancestors().skip(1) {
+        let dir: PathBuf = root.join(parent);
+        let file: PathBuf = dir.join(".gitignore");
+        if !file.is_file() {
+            continue;
+        }
+        let mut builder: GitignoreBuilder = GitignoreBuilder::new(&dir);
+        let _ = builder.add(&file);
+        let ignore: Gitignore = builder.build().unwrap_or_else(|_| Gitignore::empty());
+        let Ok(inner) = path.strip_prefix(&dir) else {
+            continue;
+        };
+        match ignore.matched_path_or_any_parents(inner, directory) {
+            Match::None => {}
+            matched => return matched.is_ignore(),
+        }
+    }
+    false
+}
+
+#[must_use]
+#[doc = "Get the languages owning at least one of the changed paths"]
+pub fn languages(paths: &[PathBuf]) -> Vec<Language> {
+    Support::new()
+        .supported()
+        .into_iter()
+        .filter(|language| paths.iter().any(|path| language.matches(path)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, process};
+
+    #[test]
+    fn nested_gitignores_are_honored() {
+        let root: PathBuf = PathBuf::from(format!("target/zuu-watch-{}", process::id()));
+        for (file, content) in [
+            (".gitignore", "*.log\ndist/\n"),
+            ("web/.gitignore", "cache/\n!keep.log\n"),
+            ("web/src/app.js", ""),
+            ("web/cache/index.js", ""),
+            ("web/keep.log", ""),
+            ("api/dist/main.js", ""),
+        ] {
+            let path: PathBuf = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let root: PathBuf = root.canonicalize().unwrap();
+        let checked: Vec<(&str, bool)> = [
+            "web/src/app.js",
+            "web/cache/index.js",
+            "web/keep.log",
+            "api/debug.log",
+            "api/dist/main.js",
+            "api/src/main.rs",
+            "zuu/stdout/test.txt",
+            ".git/index",
+        ]
+        .into_iter()
+        .map(|file| (file, ignored(&root, &root.join(file))))
+        .collect();
+        let outside: bool = ignored(&root, Path::new("/tmp/elsewhere.rs"));
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            checked,
+            [
+                ("web/src/app.js", false),
+                ("web/cache/index.js", true),
+                ("web/keep.log", false),
+                ("api/debug.log", true),
+                ("api/dist/main.js", true),
+                ("api/src/main.rs", false),
+                ("zuu/stdout/test.txt", true),
+                (".git/index", true),
+            ]
+        );
+        assert!(outside);
+    }
+
+    #[test]
+    fn languages_keep_the_owners_of_the_changes() {
+        let paths: Vec<PathBuf> = vec![
+            PathBuf::from("src/main.rs"),
+            PathBuf::from("web/package.json"),
+            PathBuf::from("README.md"),
+        ];
+        assert_eq!(
+            languages(&paths),
+            [Language::Rust, Language::JavaScript, Language::TypeScript]
+        );
+        assert!(languages(&[PathBuf::from("docs/notes.txt")]).is_empty());
+        assert!(languages(&[]).is_empty());
+    }
+}